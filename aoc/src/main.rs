@@ -0,0 +1,295 @@
+/*
+ Advent of Code 2024 runner
+
+ Runs any day's solution through its library instead of going into each day folder and using `cargo run`.
+
+ Usage:
+
+   aoc run <day|all> [options]
+   aoc verify <day|all>
+   aoc check <day|all> [--record]
+   aoc report
+
+ `run` accepts the same options each day does, see `aoc --help`. `run all` skips the days whose input files are empty.
+ With `--bench <runs>` it also prints a table of the days' timings at the end.
+ `verify` solves every example listed in the days' `examples.toml` and checks their answers.
+ `check` also solves every input with answers accepted in the days' `answers.toml` and exits with an error when any of them changed.
+ With `--record` the current answers of the inputs (the puzzle one when there are none yet) are accepted instead.
+ `report` prints the timings table for every day with a JSON summary in `target/bench`, e.g. after benchmarking days one at a time.
+*/
+use std::env;
+use std::process;
+
+use utils::{Answers, ArgsError, BenchSummary, ChallengeConfig, ChallengePart, Error, InputSource, Solution, OPTIONS_USAGE};
+
+const USAGE: &str = "Usage: aoc run <day|all> [options]\n       aoc verify <day|all>\n       aoc check <day|all> [--record]\n       aoc report";
+
+/// What the runner does with each day.
+enum Command {
+  /// Runs the days, only reporting the ones without input when running several of them.
+  Run { config: ChallengeConfig, skip_missing_input: bool },
+  Verify,
+  Check { record: bool },
+}
+
+impl Command {
+  /// Runs the command for a day's solution and returns whether it succeeded.
+  fn run<S: Solution>(&self) -> bool {
+    match self {
+      Command::Run { config, skip_missing_input } => run::<S>(config, *skip_missing_input),
+      Command::Verify => verify::<S>(),
+      Command::Check { record } => check::<S>(*record),
+    }
+  }
+}
+
+fn main() {
+  let args: Vec<String> = env::args().skip(1).collect();
+
+  if args.first().is_some_and(|command| command == "report") {
+    process::exit(if report(1..=25) { 0 } else { 1 });
+  }
+
+  let (command, days) = match parse_args(&args) {
+    Ok(parsed) => parsed,
+    Err(ArgsError::Help) => {
+      println!("{USAGE}\n\n{OPTIONS_USAGE}");
+      process::exit(0);
+    },
+    Err(ArgsError::Invalid(message)) => {
+      eprintln!("{message}\n\n{USAGE}\n\n{OPTIONS_USAGE}");
+      process::exit(2);
+    }
+  };
+
+  let mut failed = false;
+  for day in &days {
+    failed |= !run_day(*day, &command);
+  }
+
+  if matches!(&command, Command::Run { config, .. } if config.bench.is_some()) {
+    failed |= !report(days);
+  }
+
+  if failed {
+    process::exit(1);
+  }
+}
+
+/// Parses the runner arguments into the command and the days to run it for.
+fn parse_args(args: &[String]) -> Result<(Command, Vec<u8>), ArgsError> {
+  let mut args = args.iter();
+
+  let command = match args.next().map(|arg| arg.as_str()) {
+    Some(command @ ("run" | "verify" | "check")) => command,
+    Some("-h" | "--help") => return Err(ArgsError::Help),
+    Some(command) => return Err(ArgsError::Invalid(format!("Unknown command: {command}"))),
+    None => return Err(ArgsError::Invalid("Missing command".to_string())),
+  };
+
+  let days = match args.next().map(|arg| arg.as_str()) {
+    Some("all") => (1..=25).collect(),
+    Some("-h" | "--help") => return Err(ArgsError::Help),
+    Some(day) => match day.parse::<u8>() {
+      Ok(day) if (1..=25).contains(&day) => vec![day],
+      _ => return Err(ArgsError::Invalid(format!("Invalid day: {day}"))),
+    },
+    None => return Err(ArgsError::Invalid("Missing day".to_string())),
+  };
+
+  let command = match command {
+    "run" => Command::Run { config: ChallengeConfig::from_args(args.by_ref().cloned())?, skip_missing_input: days.len() > 1 },
+    "check" => match args.next().map(|arg| arg.as_str()) {
+      Some("--record") => Command::Check { record: true },
+      Some(arg) => return Err(ArgsError::Invalid(format!("Unknown argument: {arg}"))),
+      None => Command::Check { record: false },
+    },
+    _ => Command::Verify,
+  };
+
+  if let Some(arg) = args.next() {
+    return Err(ArgsError::Invalid(format!("Unknown argument: {arg}")));
+  }
+
+  Ok((command, days))
+}
+
+/// Runs the command for the given day and returns whether it succeeded.
+fn run_day(day: u8, command: &Command) -> bool {
+  match day {
+    1 => command.run::<day1::HistorianHysteria>(),
+    2 => command.run::<day2::RedNoseReports>(),
+    3 => command.run::<day3::MullItOver>(),
+    4 => command.run::<day4::CeresSearch>(),
+    5 => command.run::<day5::PrintQueue>(),
+    6 => command.run::<day6::GuardGallivant>(),
+    7 => command.run::<day7::BridgeRepair>(),
+    8 => command.run::<day8::ResonantCollinearity>(),
+    9 => command.run::<day9::DiskFragmenter>(),
+    10 => command.run::<day10::HoofIt>(),
+    11 => command.run::<day11::PlutonianPebbles>(),
+    12 => command.run::<day12::GardenGroups>(),
+    13 => command.run::<day13::ClawContraption>(),
+    14 => command.run::<day14::RestroomRedoubt>(),
+    15 => command.run::<day15::WarehouseWoes>(),
+    16 => command.run::<day16::ReindeerMaze>(),
+    17 => command.run::<day17::ChronospatialComputer>(),
+    18 => command.run::<day18::RamRun>(),
+    19 => command.run::<day19::LinenLayout>(),
+    20 => command.run::<day20::RaceCondition>(),
+    21 => command.run::<day21::KeypadConundrum>(),
+    22 => command.run::<day22::MonkeyMarket>(),
+    23 => command.run::<day23::LanParty>(),
+    24 => command.run::<day24::CrossedWires>(),
+    25 => command.run::<day25::CodeChronicle>(),
+    _ => unreachable!("Days are validated when parsing the arguments"),
+  }
+}
+
+fn run<S: Solution>(config: &ChallengeConfig, skip_missing_input: bool) -> bool {
+  if !config.quiet && config.bench.is_some() {
+    println!("------ Day {}: {}; Benchmarking; Using: {} input -------", S::DAY, S::TITLE, config.input_name());
+  } else if !config.quiet {
+    println!(
+      "------ Day {}: {}; Running part: {:?}; Using: {} input -------",
+      S::DAY,
+      S::TITLE,
+      config.part,
+      config.input_name()
+    );
+  }
+
+  match utils::solve::<S>(config) {
+    Ok(()) => true,
+    Err(error @ Error::MissingInput(_)) if skip_missing_input => {
+      eprintln!("Day {} skipped: {error}", S::DAY);
+      true
+    },
+    Err(error) => {
+      eprintln!("Day {} failed: {error}", S::DAY);
+      false
+    }
+  }
+}
+
+fn verify<S: Solution>() -> bool {
+  println!("------ Day {}: {}; Verifying examples -------", S::DAY, S::TITLE);
+
+  verify_examples::<S>()
+}
+
+/// Prints the table of the benchmark summaries of the given days, skipping the ones that weren't benchmarked.
+fn report(days: impl IntoIterator<Item = u8>) -> bool {
+  let mut summaries = vec![];
+  for day in days {
+    match BenchSummary::load(day) {
+      Ok(Some(summary)) => summaries.push(summary),
+      Ok(None) => {},
+      Err(error) => {
+        eprintln!("Day {day} failed: {error}");
+        return false;
+      }
+    }
+  }
+
+  if summaries.is_empty() {
+    println!("No benchmark summaries in {}", BenchSummary::dir().display());
+  } else {
+    println!("{}", utils::bench_table(&summaries));
+  }
+
+  true
+}
+
+/// Checks the answers of the examples and of the inputs with accepted answers, or records the latter.
+fn check<S: Solution>(record: bool) -> bool {
+  println!("------ Day {}: {}; Checking answers -------", S::DAY, S::TITLE);
+
+  let examples_passed = verify_examples::<S>();
+
+  let mut answers = match Answers::load(S::DAY) {
+    Ok(answers) => answers,
+    Err(error) => {
+      eprintln!("Day {} failed: {error}", S::DAY);
+      return false;
+    }
+  };
+
+  let mut inputs: Vec<String> = answers.inputs().map(|input| input.to_string()).collect();
+  if inputs.is_empty() {
+    if !record {
+      println!("No accepted answers");
+      return examples_passed;
+    }
+    inputs.push("puzzle".to_string());
+  }
+
+  let mut passed = examples_passed;
+  for input in &inputs {
+    let source = match input.as_str() {
+      "puzzle" => InputSource::Files,
+      file => InputSource::Path(ChallengeConfig::input_dir(S::DAY).join(file)),
+    };
+    let config = ChallengeConfig { part: ChallengePart::Both, input: source, quiet: true, ..ChallengeConfig::default() };
+
+    let solved = match utils::answers::<S>(&config) {
+      Ok(solved) => solved,
+      // there's nothing to record until the input is added
+      Err(error @ Error::MissingInput(_)) if record => {
+        println!("Input {input}: {error}, nothing recorded");
+        continue;
+      },
+      Err(error) => {
+        eprintln!("Day {} failed: {error}", S::DAY);
+        passed = false;
+        continue;
+      }
+    };
+
+    let accepted = answers.get(input);
+    for (part, answer) in &solved {
+      if record {
+        answers.record(input, *part, answer);
+        println!("Input {input} part {part:?}: {answer} recorded");
+      } else {
+        let status = accepted.check(*part, answer);
+        println!("Input {input} part {part:?}: {answer} {status}");
+        passed &= !status.is_regression();
+      }
+    }
+  }
+
+  if record {
+    if let Err(error) = answers.save() {
+      eprintln!("Day {} failed: {error}", S::DAY);
+      return false;
+    }
+  }
+
+  passed
+}
+
+/// Solves the examples with expected answers and prints how each of them went.
+fn verify_examples<S: Solution>() -> bool {
+  let checks = match utils::verify::<S>() {
+    Ok(checks) => checks,
+    Err(error) => {
+      eprintln!("Day {} failed: {error}", S::DAY);
+      return false;
+    }
+  };
+
+  if checks.is_empty() {
+    println!("No examples with expected answers");
+  }
+
+  for check in &checks {
+    if check.passed() {
+      println!("Example {} part {:?}: {} ok", check.example, check.part, check.answer);
+    } else {
+      println!("Example {} part {:?}: {} FAILED, expected {}", check.example, check.part, check.answer, check.expected);
+    }
+  }
+
+  checks.iter().all(|check| check.passed())
+}
@@ -0,0 +1,102 @@
+/*
+ Advent of Code 2024 Day 1: Historian Hysteria
+
+ Part one:
+
+ Calculate the distances of the location lists.
+ That is pairing the smallest number of one list to the smallest of the other and then next smallest and so forth.
+ Then calculate the distance between both numbers e.g. 9 and 3 is 6, whatever the order.
+ Then add up all distances together and voila.
+
+ Part two:
+
+ Calculate how often each number from the left list appears in the right list.
+ It is needed to calculate the similarity score which tis the number of the left column times the times it appears on the right.
+ All added together for each element of the left list.
+*/
+use std::fmt::Display;
+
+use utils::{parse_field, PuzzleInput, Result, Solution};
+
+pub struct HistorianHysteria;
+
+impl Solution for HistorianHysteria {
+  const DAY: u8 = 1;
+  const TITLE: &'static str = "Historian Hysteria";
+
+  type Input = LocationLists;
+
+  fn parse(input: &PuzzleInput) -> Result<Self::Input> {
+    parse_puzzle_input(input)
+  }
+
+  fn part_one(lists: &Self::Input) -> impl Display {
+    calculate_distance(&mut lists.left.clone(), &mut lists.right.clone())
+  }
+
+  fn part_two(lists: &Self::Input) -> impl Display {
+    calculate_similarity_score(&lists.left, &lists.right)
+  }
+}
+
+/// The two lists of location IDs, one per column of the input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocationLists {
+  pub left: Vec<i32>,
+  pub right: Vec<i32>,
+}
+
+pub fn parse_puzzle_input(input: &str) -> Result<LocationLists> {
+  let mut column1: Vec<i32> = Vec::new();
+  let mut column2: Vec<i32> = Vec::new();
+
+  for (idx, line) in input.lines().enumerate() {
+      let columns: Vec<&str> = line.split_whitespace().collect();
+
+      if columns.len() >= 2 {
+          let col1: i32 = parse_field(line, idx + 1, columns[0])?;
+          let col2: i32 = parse_field(line, idx + 1, columns[1])?;
+
+          column1.push(col1);
+          column2.push(col2);
+      }
+  }
+
+  Ok(LocationLists { left: column1, right: column2 })
+}
+
+pub fn calculate_distance(column1: &mut [i32], column2: &mut [i32]) -> i32 {
+    // Sort the lists
+    column1.sort();
+    column2.sort();
+
+    let mut results: Vec<i32> = Vec::new();
+
+    for (left, right) in column1.iter().zip(column2.iter()) {
+        results.push((left - right).abs());
+    }
+
+    // println!("Results: {:?}", results); // Output: [3, 3, 3, 4, 5, 9]
+
+    results.iter().sum::<i32>()
+}
+
+// To resolve part two
+pub fn calculate_similarity_score(column1: &[i32], column2: &[i32]) -> i32 {
+    let mut results: Vec<i32> = Vec::new();
+
+    for left in column1 {
+        let count: i32 = column2
+            .iter()
+            .filter(|&x| x == left)
+            .count()
+            .try_into()
+            .unwrap();
+
+        results.push(count * left);
+    }
+
+    // println!("Results: {:?}", results); // Output: [3, 3, 3, 4, 5, 9]
+
+    results.iter().sum::<i32>()
+}
@@ -0,0 +1,108 @@
+/*
+ Advent of Code 2024 Day 10
+
+ Part one:
+ 
+ the input is a topographic map that indicate the `height` at each position using a scale of 0 to 9 (highest).
+ a `hiking trail` is a longest possible, even ,gradual uphill slope. i.e. starts at 0 ends at 9 and always increases at a height of 1 at each step.
+ The steps can only be up, down, left or right. 
+ a `trailhead` is any position that starts a one or more hiking trails.
+ a trailhead's score is the number of unique 9 height positions reachable from that trailhead via a hiking trail.
+ the result is the sum of the scores of all trailheads.
+
+ Part two:
+ now the result is the number of distinct hiking trails which begin at a certain trailhead.
+ aka doesn't mattter if it arrives to hte same 9, just how many ways it has to reach to 9s
+*/
+use std::fmt::Display;
+
+use utils::pathfinding::{bfs, Search};
+use utils::{Coordinate, Grid, PuzzleInput, Result, Solution};
+
+pub struct HoofIt;
+
+impl Solution for HoofIt {
+  const DAY: u8 = 10;
+  const TITLE: &'static str = "Hoof It";
+
+  type Input = TopographicMap;
+
+  fn parse(input: &PuzzleInput) -> Result<Self::Input> {
+    parse_input(input)
+  }
+
+  fn part_one(topographic_map: &Self::Input) -> impl Display {
+    trailheads_score(topographic_map.clone())
+  }
+
+  fn part_two(topographic_map: &Self::Input) -> impl Display {
+    trailheads_ratings(topographic_map.clone())
+  }
+}
+
+pub type TopographicMap = Grid<char>;
+
+pub fn parse_input(input: &str) -> Result<TopographicMap> {
+  Grid::from_lines(input.lines())
+}
+
+pub fn trailheads_score(topographic_map: TopographicMap) -> usize {
+  let trailheads = get_trailheads(&topographic_map);
+
+  let mut trailheads_score = 0;
+  for trailhead in trailheads {
+    trailheads_score += get_trailhead_score(&trailhead, &topographic_map);
+  }
+
+  trailheads_score
+}
+
+pub fn trailheads_ratings(topographic_map: TopographicMap) -> usize {
+  let trailheads = get_trailheads(&topographic_map);
+
+  let mut trailheads_ratings = 0;
+  for trailhead in trailheads {
+    trailheads_ratings += get_trailhead_rating(&trailhead, &topographic_map);
+  }
+
+  trailheads_ratings
+}
+
+fn get_trailheads(topographic_map: &TopographicMap) -> Vec<Coordinate> {
+  topographic_map
+    .iter()
+    .filter(|(_, height)| **height == '0')
+    .map(|(coordinate, _)| coordinate)
+    .collect()
+}
+
+fn get_trailhead_score(trailhead: &Coordinate, topographic_map: &TopographicMap) -> usize {
+  hiking_trails(trailhead, topographic_map).goals.len()
+}
+
+fn get_trailhead_rating(trailhead: &Coordinate, topographic_map: &TopographicMap) -> usize {
+  hiking_trails(trailhead, topographic_map).all_paths().len()
+}
+
+// Every step is one higher, so every top is reached at the same distance and the search finds all of them and all the trails there.
+fn hiking_trails(trailhead: &Coordinate, topographic_map: &TopographicMap) -> Search<Coordinate> {
+  bfs(trailhead.clone(), |location| get_next_possible_locations(location, topographic_map), |location| topographic_map[location] == '9')
+}
+
+fn get_next_possible_locations(location: &Coordinate, topographic_map: &TopographicMap) -> Vec<Coordinate> {
+  topographic_map
+    .neighbours(location)
+    .filter(|next_location| !is_wall(next_location, topographic_map) && is_evenly_higher(next_location, location, topographic_map))
+    .collect()
+}
+
+fn is_evenly_higher(next_location: &Coordinate, current_location: &Coordinate, topographic_map: &TopographicMap) -> bool {
+  let next_location = topographic_map[next_location].to_digit(10).unwrap() as i32;
+  let current_location = topographic_map[current_location].to_digit(10).unwrap() as i32;
+
+  next_location - current_location == 1 
+}
+
+fn is_wall(next_location: &Coordinate, topographic_map: &TopographicMap) -> bool {
+  topographic_map[next_location] == '.'
+}
@@ -0,0 +1,23 @@
+/*
+ Compares blinking the stones one by one split in threads (`after_n_blinks`)
+ with counting how many stones of each number there are (`after_n_blinks_map`), for part one's 25 blinks.
+*/
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use day11::{after_n_blinks, after_n_blinks_map, PlutonianPebbles};
+use utils::{ChallengeConfig, TEST_CONFIG};
+
+fn compare_blinks(c: &mut Criterion) {
+  let example = TEST_CONFIG.load::<PlutonianPebbles>().unwrap().stones;
+  let puzzle = ChallengeConfig::default().load::<PlutonianPebbles>().unwrap().stones;
+
+  for (input, stones) in [("example", example), ("puzzle", puzzle)] {
+    let mut group = c.benchmark_group(format!("{input} 25 blinks"));
+    group.bench_function("threads", |b| b.iter(|| after_n_blinks(25, stones.clone())));
+    group.bench_function("map", |b| b.iter(|| after_n_blinks_map(25, stones.clone())));
+    group.finish();
+  }
+}
+
+criterion_group!(benches, compare_blinks);
+criterion_main!(benches);
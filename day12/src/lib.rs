@@ -0,0 +1,212 @@
+/*
+ Advent of Code 2024 Day 12: Garden Groups
+
+ The input is a map of garden plots. Each garden plot grows a signle type of plant indicated by a single letter on the map.
+ The plots form regions (when the same plant is touching horizontally or vertically with another plot)
+ Each region has an area and a perimeter.
+ An area is just the amount of plots of the region. The perimeter is only the sides that touch another region (our map side)
+ Regions can appear within regions. And plants of the same type can appear in multiple separate regions.
+ The `price` of fence required for a region is found by multiplying the region are by its perimeter.
+ The `total price` of fencing all regions is the sum of all fence price of every region.
+
+ Part one:
+
+ What is the total price of fencing all regions on your map?
+
+ Part two:
+
+ To calculate the perimeter this time you use the sides of each region has.
+ What would be the new total price?
+
+ Solution: 
+
+ For the first solutions I map the input into a hashmap of coordinates and plants. From this one I start creating regions and merging existing ones if necessary.
+ For the second part found a solution in internet that made me realize I just had to check for each element its four corners.
+ if any corner is an inner or outer edge you add up a point, as the sum of all edges is the number of sides in a polygon.
+ That made the calculation easy. Still the first part of making the regions is not very performant but it works. 
+ It is not performant specially because I'm trying to merge regions every time I check for an item and that is costly.
+
+*/
+use std::fmt::Display;
+
+use utils::{Coordinate, Direction, Grid, PuzzleInput, Result, Solution};
+
+pub struct GardenGroups;
+
+impl Solution for GardenGroups {
+  const DAY: u8 = 12;
+  const TITLE: &'static str = "Garden Groups";
+
+  /// The regions are the costly part, so they are only built once for both parts.
+  type Input = Vec<Region>;
+
+  fn parse(input: &PuzzleInput) -> Result<Self::Input> {
+    Ok(get_regions(&parse_plots(input)?))
+  }
+
+  fn part_one(regions: &Self::Input) -> impl Display {
+    calculate_price(regions, Region::price)
+  }
+
+  fn part_two(regions: &Self::Input) -> impl Display {
+    calculate_price(regions, Region::price_with_discount)
+  }
+}
+
+pub fn parse_plots(input: &str) -> Result<Grid<char>> {
+  Grid::from_lines(input.lines())
+}
+
+#[derive(Debug, Clone)]
+pub struct Region {
+  pub plant: char,
+  pub plots: Vec<Coordinate>,
+  pub area: i32,
+  pub perimeter: i32,
+}
+
+impl Region {
+  fn new(plant: char, coordinate: &Coordinate) -> Self {
+    Self {
+      plant,
+      plots: vec![coordinate.clone()],
+      area: 1,
+      perimeter: 4,
+    }
+  }
+
+  pub fn price(&self) -> i32 {
+    self.area * self.perimeter
+  }
+
+  pub fn price_with_discount(&self) -> i32 {
+    self.area * self.sides()
+  }
+
+  pub fn sides(&self) -> i32 {
+    let mut sides = 0;
+
+    for plot in &self.plots {
+      let surrounding_plots = get_surrounding_plots(plot.clone(), &self.plots);
+      sides += get_side_delta(plot.clone(), &surrounding_plots);
+    }
+
+    // println!("plant: {}, plots: {:?}, sides: {}", self.plant, self.plots, sides);
+
+
+    sides
+  }
+
+  fn is_adjacent(&self, coordinate: &Coordinate) -> bool {
+    for direction in Direction::iter() {
+      let next = coordinate.add_delta(&direction);
+
+      if self.plots.contains(&next) {
+        return true;
+      } 
+    }
+
+    false
+  }
+
+  fn add_plot(&mut self, plot: &Coordinate) {
+    let adjacent_plots = self.plots
+      .iter()
+      .filter(|other_plot| other_plot.is_adjacent(plot))
+      .count();
+
+    self.perimeter += 4 - 2 * adjacent_plots as i32;
+    self.area += 1;
+    self.plots.push(plot.clone());
+  }
+
+  fn merge_region(&mut self, other_region: &Region) {
+    for plot in other_region.clone().plots {
+      self.add_plot(&plot);
+    }
+  }
+}
+
+pub fn get_regions(plot_map: &Grid<char>) -> Vec<Region> {
+  let mut regions: Vec<Region> = Vec::new();
+
+  for (coordinate, &plant) in plot_map.iter() {
+    // println!("Regions: {:?}", regions);
+    // println!("plant: {plant}, coordinate: {coordinate:?}");
+
+    if regions.is_empty() {
+      regions.push(Region::new(plant, &coordinate));
+      continue;
+    }
+
+    let mut other_regions = Vec::new();
+    let mut adjacent_regions = Vec::new();
+    for region in regions.clone() {
+      if region.plant == plant && region.is_adjacent(&coordinate) {
+        adjacent_regions.push(region.clone());
+      } else {
+        other_regions.push(region.clone());
+      }
+    } 
+
+    if !adjacent_regions.is_empty() {
+      // println!("adjacent regions: {:?}\nother regions: {:?}\n", adjacent_regions, other_regions);
+      let (new_region, rest) = adjacent_regions.split_first_mut().unwrap();
+      new_region.add_plot(&coordinate);
+
+      for region in rest {
+        new_region.merge_region(region);
+      }
+
+      other_regions.push(new_region.clone());
+      regions = other_regions.clone();
+    } else {
+      regions.push(Region::new(plant, &coordinate));
+    }
+  }
+
+  regions
+}
+
+pub fn calculate_price(regions: &[Region], price: impl Fn(&Region) -> i32) -> i32 {
+  let mut total_price = 0;
+  for region in regions {
+    // println!("Region plant: {} area: {} perimeter: {}  sides: {}", region.plant, region.area, region.perimeter, region.sides());
+    total_price += price(region);
+  }
+  total_price
+}
+
+fn get_surrounding_plots(plot: Coordinate, other_plots: &[Coordinate]) -> Vec<Coordinate> {
+  let deltas = [
+    (0,1),    // right
+    (0, -1),  // left
+    (1,0),   // down
+    (-1, 0),  // up
+    (1, 1),    // diagonal down right
+    (1, -1), // diagonal down left
+    (-1,-1),  // diagonal up left
+    (-1, 1),  // diagonal up right
+  ];
+
+  let mut surrounding_plot = vec![];
+  for delta in deltas {
+    let next_coordinate = Coordinate { x: plot.x + delta.0, y: plot.y + delta.1 };
+    if other_plots.contains(&next_coordinate) && next_coordinate != plot {
+      surrounding_plot.push(next_coordinate);
+    }
+  }
+
+  surrounding_plot
+}
+
+fn get_side_delta(plot: Coordinate, surrounding_plots: &[Coordinate]) -> i32 {
+  let mut corners = 0;
+  for corner in [Direction::UpRight, Direction::UpLeft, Direction::DownLeft, Direction::DownRight] {
+    if plot.is_outer_edge(&corner, surrounding_plots) || plot.is_inner_edge(&corner, surrounding_plots) {
+      corners += 1;
+    }
+  }
+
+  corners
+}
\ No newline at end of file
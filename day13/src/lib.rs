@@ -0,0 +1,145 @@
+/*
+ Advent of Code 2024 Day 13: Claw Contraption
+
+ There is a claw machine that has only two buttons: `A` and `B`
+ It costs 3 tokens to push the A button and 1 token to push the B button.
+ Each machine's buttones have a specific configurtion to move the claw to the right (X axis) or forward (Y axis) a specific amount each time a button is pressed.
+ Each machine containes one prize. to win the prize the claw must be exactly aboeve the prize on both X and Y axes.
+ No button needs to be pressed more than 100 times to win a prize.
+ There could be combinations that are not possible.
+
+ Part one:
+
+ What is the fewest tokens you would have to spend to win all possible prizes?
+
+ Part two:
+
+ add to the prize locations 10_000_000_000_000 and calculate the tokens again
+
+ Solution:
+
+ For this challenge I used linear programming to solve for one of the tokens variables. Even though I started trying to brute force by checking each button a and b token combination that satisfied the equation.
+ I then restorted to the math approach for part tow as it wouldn't have scaled previous algorithm.
+*/
+use std::fmt::Display;
+
+use utils::{parse_field, Error, PuzzleInput, Result, Solution};
+use regex::Regex;
+
+pub struct ClawContraption;
+
+impl Solution for ClawContraption {
+  const DAY: u8 = 13;
+  const TITLE: &'static str = "Claw Contraption";
+
+  type Input = Vec<ClawMachineConfig>;
+
+  fn parse(input: &PuzzleInput) -> Result<Self::Input> {
+    parse_input(input)
+  }
+
+  fn part_one(configs: &Self::Input) -> impl Display {
+    calculate_minimum_tokens(configs, 0)
+  }
+
+  fn part_two(configs: &Self::Input) -> impl Display {
+    calculate_minimum_tokens(configs, 10_000_000_000_000)
+  }
+}
+
+#[derive(Debug)]
+pub struct Location {
+  pub x: i64,
+  pub y: i64,
+}
+
+#[derive(Debug)]
+pub struct ButtonConfig {
+  pub a: Location,
+  pub b: Location,
+} // The button configuratioon can be stored similar to a location but have different meaning.
+
+#[derive(Debug)]
+pub struct ClawMachineConfig {
+  pub prize: Location,
+  pub button_config: ButtonConfig,
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<ClawMachineConfig>> {
+  let mut claw_machine_configs = Vec::new();
+
+  let patterns = [
+    ("Button A: X+<n>, Y+<n>", Regex::new(r"^Button A: X\+(\d+), Y\+(\d+)$").unwrap()),
+    ("Button B: X+<n>, Y+<n>", Regex::new(r"^Button B: X\+(\d+), Y\+(\d+)$").unwrap()),
+    ("Prize: X=<n>, Y=<n>", Regex::new(r"^Prize: X=(\d+), Y=(\d+)$").unwrap()),
+  ];
+
+  // Each machine is described by its button A, button B and prize lines in that order.
+  let mut locations = Vec::new();
+  for (idx, line) in input.lines().enumerate() {
+    if line.trim().is_empty() {
+      continue;
+    }
+
+    let (expected, pattern) = &patterns[locations.len()];
+    let captured = pattern
+      .captures(line)
+      .ok_or_else(|| Error::parse(idx + 1, 1, format!("Expected `{expected}`")))?;
+
+    locations.push(Location {
+      x: parse_field(line, idx + 1, captured.get(1).unwrap().as_str())?,
+      y: parse_field(line, idx + 1, captured.get(2).unwrap().as_str())?,
+    });
+
+    if locations.len() == patterns.len() {
+      let mut locations = locations.drain(..);
+
+      claw_machine_configs.push(ClawMachineConfig {
+        button_config: ButtonConfig { a: locations.next().unwrap(), b: locations.next().unwrap() },
+        prize: locations.next().unwrap(),
+      });
+    }
+  }
+
+  if !locations.is_empty() {
+    let (expected, _) = &patterns[locations.len()];
+    return Err(Error::parse(input.lines().count() + 1, 1, format!("Expected `{expected}`")));
+  }
+
+  Ok(claw_machine_configs)
+}
+
+pub fn calculate_minimum_tokens(configs: &[ClawMachineConfig], correction: i64) -> i64 {
+  let mut minimum_tokens_needed = 0;
+  for config in configs {
+    minimum_tokens_needed += get_tokens(config, correction);
+  }
+
+  minimum_tokens_needed
+}
+
+fn get_tokens(config: &ClawMachineConfig, correction: i64) -> i64 {
+  let w = config.prize.x + correction;
+  let y = config.prize.y + correction;
+  let a = config.button_config.a.x;
+  let b = config.button_config.b.x;
+  let c = config.button_config.a.y;
+  let d = config.button_config.b.y;
+
+  let m = (c * w - a * y) / ( c * b - a * d);
+  let n = (y - m * d) / c;
+
+  // println!("m: {m}, n: {n}");
+
+  let result_x = n * a + m * b;
+  let result_y = n * c + m * d;
+
+  // println!("X={w}, Y={y}");
+  // println!("X={result_x},Y={result_y}");
+
+  if result_x == w && result_y == y {
+    return n * 3 + m;
+  }
+
+  0
+}
\ No newline at end of file
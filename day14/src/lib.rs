@@ -0,0 +1,268 @@
+use regex::Regex;
+/*
+ Advent of Code 2024 Day 14: Restroom Redoubt
+
+ There are robots that move in predictable straight lines.
+ The input is a list of all of the robots current positions (p) and velocities (v).
+ Each position is given as p=x,y where x is the number of tiles from the left wall and y the number of tiles from the top wall. (when viewed from above)
+ Velocities are given in a similar fashion where vx is to the right, if positive, and vy to the bottom.
+ Robots can share same tiles and wrap around edges.  
+ The floor map is divided into quadrants. The `safety factor` is the multiplicatation of the amount of robots per quadrant.
+ Robots that are exactly in the middle horizontally or vertically, do not count.
+ Our puzzle map is 101 wide x 103 tall.
+
+ Part one:
+
+ What will the safety factor be after exactly 100 seconds have elapsed.
+
+ Part two:
+
+ There is an easter egg where the robots are forming a christmass tree. 
+ What is the fewest number of seconds that must elapse for the robots to display the Easter egg?
+
+ Solution:
+
+ unfortunately for part two first time I had to copy the solution of davidkna https://www.reddit.com/r/adventofcode/comments/1hdvhvu/comment/m213uxb/?utm_source=share&utm_medium=web3x&utm_name=web3xcss&utm_term=1&utm_content=share_button
+ as my printing wasn't showing the correct numbers. His solution worked at the first attempt.
+ I noticed later that I was calculating positions wrongly, corrected that and found the image where it should have been.
+
+ In general, the idea is to calculate using modulus arithmetic the possition after n secodns of the robots and multiply the robots of each quadrant. That worked initially but my heuristic for finding the christmas tree didn't.n
+ Now the tree is found without looking at the frames: it's the second where the robots' positions vary the least on both axes,
+ and as each axis repeats with the width or height of the room only that many seconds need to be checked.
+*/
+use std::fmt::Display;
+
+use utils::{parse_field, Error, PuzzleInput, Result, Solution};
+
+pub struct RestroomRedoubt;
+
+impl Solution for RestroomRedoubt {
+  const DAY: u8 = 14;
+  const TITLE: &'static str = "Restroom Redoubt";
+
+  type Input = Restroom;
+
+  fn parse(input: &PuzzleInput) -> Result<Self::Input> {
+    let robot_list = parse_robot_list(input)?;
+    // println!("robot_list: {robot_list:?}");
+
+    // The puzzle's room is 101x103, examples set their own size with the `width` and `height` params. i.e. 11x7.
+    let room_dimension = RoomDimension {
+      x: input.param_or("width", 101)?,
+      y: input.param_or("height", 103)?,
+    };
+    // the robots wrap around the room, which can't be done in an empty one
+    for (name, size) in [("width", room_dimension.x), ("height", room_dimension.y)] {
+      if size < 1 {
+        return Err(Error::InvalidParam { name: name.to_string(), cause: format!("The room has to be at least 1 tile wide and high, not {size}") });
+      }
+    }
+
+    Ok(Restroom { robots: robot_list, room: room_dimension })
+  }
+
+  fn part_one(restroom: &Self::Input) -> impl Display {
+    safety_factor(&restroom.robots, 100, &restroom.room)
+  }
+
+  fn part_two(restroom: &Self::Input) -> impl Display {
+    easter_egg_seconds(&restroom.robots, &restroom.room).map_or_else(|| "No Easter egg".to_string(), |seconds| seconds.to_string())
+  }
+}
+
+pub fn parse_robot_list(input: &str) -> Result<Vec<Robot>> {
+  let mut robot_list = vec![];
+  let list_regex = Regex::new(r"p=(\d+),(\d+) v=(-?\d+),(-?\d+)").unwrap();
+
+  for (idx, line) in input.lines().enumerate() {
+    let captured = list_regex
+      .captures(line)
+      .ok_or_else(|| Error::parse(idx + 1, 1, "Expected `p=<x>,<y> v=<x>,<y>`"))?;
+    let field = |group| parse_field(line, idx + 1, captured.get(group).unwrap().as_str());
+    
+    robot_list.push(Robot {
+      position: Position  {
+        x: field(1)?,
+        y: field(2)?,
+      },
+      speed: Speed {
+        x: field(3)?,
+        y: field(4)?,
+      }
+    })
+  }
+
+  Ok(robot_list)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Position {
+  pub x: i32,
+  pub y: i32,
+}
+
+pub type Speed = Position;
+pub type RoomDimension = Position;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Robot {
+  pub position: Position,
+  pub speed: Speed,
+}
+
+/// The robots in the restroom and its size.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Restroom {
+  pub robots: Vec<Robot>,
+  pub room: RoomDimension,
+}
+
+impl Robot {
+  /// Where the robot is after the given seconds, wrapping around the room's walls.
+  /// Only the seconds left after whole turns around the room matter, so any number of them takes the same time.
+  pub fn position_at(&self, seconds: u64, room_dimension: &RoomDimension) -> Position {
+    let axis = |position: i32, speed: i32, size: i32| {
+      let seconds = (seconds % size as u64) as i64;
+      (position as i64 + speed as i64 * seconds).rem_euclid(size as i64) as i32
+    };
+
+    Position {
+      x: axis(self.position.x, self.speed.x, room_dimension.x),
+      y: axis(self.position.y, self.speed.y, room_dimension.y),
+    }
+  }
+}
+
+/// Robots in each quadrant of the room after the given seconds: top left, top right, bottom left and bottom right.
+/// The ones exactly in the middle horizontally or vertically are left out.
+pub fn quadrant_counts(robot_list: &[Robot], seconds: u64, room_dimension: &RoomDimension) -> [usize; 4] {
+  let vertical_half = room_dimension.y / 2;
+  let horizontal_half = room_dimension.x / 2;
+  let mut quadrants = [0; 4];
+
+  for robot in robot_list {
+    let position = robot.position_at(seconds, room_dimension);
+    if position.x == horizontal_half || position.y == vertical_half {
+      continue;
+    }
+
+    let quadrant = match (position.x < horizontal_half, position.y < vertical_half) {
+      (true, true) => 0,
+      (false, true) => 1,
+      (true, false) => 2,
+      (false, false) => 3,
+    };
+    quadrants[quadrant] += 1;
+  }
+
+  quadrants
+}
+
+pub fn safety_factor(robot_list: &[Robot], seconds: u64, room_dimension: &RoomDimension) -> usize {
+  quadrant_counts(robot_list, seconds, room_dimension).iter().product()
+}
+
+/// Seconds until the robots first display the Easter egg.
+/// In the frame with the tree most robots are close together, so it's the one where their positions vary the least on both axes.
+/// Horizontal positions repeat every `width` seconds and vertical ones every `height` seconds, so the least varying second of each axis
+/// is found on its own and then combined, never looking at more than `width + height` frames.
+/// When the width and height have a common divisor the two seconds may never match, then every frame until they both repeat is looked at.
+pub fn easter_egg_seconds(robot_list: &[Robot], room_dimension: &RoomDimension) -> Option<u64> {
+  let (width, height) = (room_dimension.x as u64, room_dimension.y as u64);
+  let axis_variance = |seconds: u64, axis: fn(&Position) -> i32| variance(robot_list.iter().map(|robot| axis(&robot.position_at(seconds, room_dimension))));
+  let (horizontal, vertical) = (|position: &Position| position.x, |position: &Position| position.y);
+
+  let least_horizontal = (0..width).min_by_key(|seconds| axis_variance(*seconds, horizontal))?;
+  let least_vertical = (0..height).min_by_key(|seconds| axis_variance(*seconds, vertical))?;
+
+  // every frame is back after `lcm(width, height)` seconds, the first matching both seconds is before
+  let period = width / gcd(width, height) * height;
+  (0..period / width)
+    .map(|cycles| least_horizontal + cycles * width)
+    .find(|seconds| seconds % height == least_vertical)
+    .or_else(|| (0..period).min_by_key(|seconds| axis_variance(*seconds, horizontal) + axis_variance(*seconds, vertical)))
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+  if b == 0 { a } else { gcd(b, a % b) }
+}
+
+// Variance of the values times their count squared, enough to compare sets of the same size without rounding.
+fn variance(values: impl Iterator<Item = i32>) -> i64 {
+  let (count, sum, squares) = values.fold((0, 0, 0), |(count, sum, squares), value| {
+    let value = value as i64;
+    (count + 1, sum + value, squares + value * value)
+  });
+
+  count * squares - sum * sum
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use utils::TEST_CONFIG;
+
+  #[test]
+  fn example_safety_factor_is_12_after_100_seconds() {
+    let restroom = TEST_CONFIG.load::<RestroomRedoubt>().unwrap();
+
+    assert_eq!(quadrant_counts(&restroom.robots, 100, &restroom.room), [1, 3, 4, 1]);
+    assert_eq!(safety_factor(&restroom.robots, 100, &restroom.room), 12);
+  }
+
+  #[test]
+  fn robots_are_back_after_turning_around_the_room() {
+    let restroom = TEST_CONFIG.load::<RestroomRedoubt>().unwrap();
+    let robot = Robot { position: Position { x: 2, y: 4 }, speed: Speed { x: 2, y: -3 } };
+
+    assert_eq!(robot.position_at(5, &restroom.room), Position { x: 1, y: 3 });
+    // every robot is back where it started after 11 * 7 seconds
+    let turns = 11 * 7 * 1_000_000_000_000_000;
+    assert_eq!(robot.position_at(turns + 5, &restroom.room), Position { x: 1, y: 3 });
+    assert_eq!(safety_factor(&restroom.robots, turns + 100, &restroom.room), 12);
+  }
+
+  #[test]
+  fn rejects_empty_rooms() {
+    let input = |width: &str| PuzzleInput::new("p=0,4 v=3,-3").with_params([("width".to_string(), width.to_string())].into());
+
+    assert!(RestroomRedoubt::parse(&input("11")).is_ok());
+    let error = RestroomRedoubt::parse(&input("0")).unwrap_err();
+    assert_eq!(error.to_string(), "Invalid parameter `width`: The room has to be at least 1 tile wide and high, not 0");
+  }
+
+  #[test]
+  fn finds_when_the_robots_gather() {
+    let room = RoomDimension { x: 11, y: 7 };
+    // every robot is at 5,3 after 20 seconds, and spread around any other second
+    let robots: Vec<Robot> = (1..=10)
+      .map(|idx| {
+        let speed = Speed { x: idx, y: (idx - 1) % 6 + 1 };
+        let position = Position { x: (5 - speed.x * 20).rem_euclid(room.x), y: (3 - speed.y * 20).rem_euclid(room.y) };
+        Robot { position, speed }
+      })
+      .collect();
+
+    assert_eq!(easter_egg_seconds(&robots, &room), Some(20));
+  }
+
+  #[test]
+  fn finds_the_robots_in_rooms_with_a_common_divisor() {
+    let room = RoomDimension { x: 6, y: 4 };
+    // every robot is at 2,1 after 7 seconds
+    let robots: Vec<Robot> = (1..=3)
+      .map(|idx| {
+        let speed = Speed { x: idx, y: idx };
+        Robot { position: Position { x: (2 - speed.x * 7).rem_euclid(room.x), y: (1 - speed.y * 7).rem_euclid(room.y) }, speed }
+      })
+      .collect();
+    assert_eq!(easter_egg_seconds(&robots, &room), Some(7));
+
+    // the robots never move horizontally, so the first second looks the best there, but they only gather vertically after 1, 5, 9... seconds
+    let robots: Vec<Robot> = (1..=3)
+      .map(|idx| Robot { position: Position { x: idx * 2 - 2, y: (1 - idx).rem_euclid(room.y) }, speed: Speed { x: 0, y: idx } })
+      .collect();
+    assert_eq!(easter_egg_seconds(&robots, &room), Some(1));
+  }
+}
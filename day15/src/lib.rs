@@ -0,0 +1,187 @@
+/*
+ Advent of Code 2024 Day 15: Warehouse Woes
+
+ The input is a map of a warehouse and a list of movements a robot will attempt to make.
+ The movements will not always succeed as the warehouse has boxes that are shifted around.
+ If the robot collides with a box (0) it will try to move it, if it is a wall (#) it will not move.
+ The movements are (^:up <: left, >:right, v: down). and the list is a giant sequence in order.
+ The boxes have GPS coordinates to track the mwhich is 100 times the distance from the top edge plus ist distance from the edge of the map. i.e. 100x + y.
+ The robot is marked with an @
+
+ Part one:
+
+ What is the sum of all boxes' GPS coordinates?
+
+ Part two:
+
+ Everything except the robot is twice as wide.
+ the distances are now measured from the edge of the map to the closest edge of the box in question.
+ These are the rules to making it wider:
+
+  If the tile is #, the new map contains ## instead.
+  If the tile is O, the new map contains [] instead.
+  If the tile is ., the new map contains .. instead.
+  If the tile is @, the new map contains @. instead.
+
+ Solution: 
+
+ Every move gathers the tiles the robot pushes, starting with the robot itself and following each box in the direction of the move.
+ When pushing wide boxes up or down both halves of a box are pushed, so a push can spread into a tree of boxes.
+ If any of them would end up in a wall nothing moves, otherwise all of them are shifted by one tile at once.
+
+*/
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use utils::{find_marker, Coordinate, Direction, Error, Grid, PuzzleInput, Result, Solution};
+
+pub struct WarehouseWoes;
+
+impl Solution for WarehouseWoes {
+  const DAY: u8 = 15;
+  const TITLE: &'static str = "Warehouse Woes";
+  const SECTIONS: &'static [&'static str] = &["map", "robot_directions"];
+
+  type Input = Warehouse;
+
+  fn parse(input: &PuzzleInput) -> Result<Self::Input> {
+    parse_puzzle_input(input.section("map")?, input.section("robot_directions")?)
+  }
+
+  fn part_one(warehouse: &Self::Input) -> impl Display {
+    sum_gps_coordinates(warehouse)
+  }
+
+  fn part_two(warehouse: &Self::Input) -> impl Display {
+    sum_gps_coordinates(&widen(warehouse))
+  }
+}
+
+/// The warehouse's map, where the robot starts and the moves it will attempt.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Warehouse {
+  pub robot: Coordinate,
+  pub map: Grid<char>,
+  pub instructions: Vec<Direction>,
+}
+
+pub fn parse_puzzle_input(map: &str, directions: &str) -> Result<Warehouse> {
+  let (puzzle_map, markers) = Grid::parse(map.lines(), &['@'], |location| location)?;
+  let robot = find_marker(&markers, '@')?;
+
+  // parse robot direction instructions map
+  let mut instructions = vec![];
+  for (idx, line) in directions.lines().enumerate() {
+    for (column, direction) in line.chars().enumerate() {
+      if !['^', 'v', '<', '>'].contains(&direction) {
+        return Err(Error::parse(idx + 1, column + 1, format!("Invalid direction `{direction}`")));
+      }
+      instructions.push(Direction::from_char(direction));
+    }
+  }
+
+  Ok(Warehouse { robot, map: puzzle_map, instructions })
+}
+
+/// Makes everything but the robot twice as wide.
+pub fn widen(warehouse: &Warehouse) -> Warehouse {
+  let tiles = warehouse.map.rows().flatten().flat_map(|location| match location {
+    '#' => ['#', '#'],
+    'O' => ['[', ']'],
+    '@' => ['@', '.'],
+    _ => ['.', '.'],
+  });
+  let map = Grid::new(warehouse.map.width() * 2, warehouse.map.height(), tiles.collect());
+
+  Warehouse {
+    robot: Coordinate::new(warehouse.robot.x, warehouse.robot.y * 2),
+    map,
+    instructions: warehouse.instructions.clone(),
+  }
+}
+
+/// Sum of the boxes' GPS coordinates once the robot is done moving, measured to the left edge of wide boxes.
+pub fn sum_gps_coordinates(warehouse: &Warehouse) -> i32 {
+  let mut robot = warehouse.robot.clone();
+  let mut puzzle_map = warehouse.map.clone();
+  for direction in &warehouse.instructions {
+    if push(&mut puzzle_map, &robot, direction) {
+      robot = robot.add_delta(direction);
+    }
+  }
+
+  puzzle_map
+    .iter()
+    .filter(|(_, location)| ['O', '['].contains(*location))
+    .map(|(coordinate, _)| coordinate.x * 100 + coordinate.y)
+    .sum()
+}
+
+// Moves whatever is at the location one tile in the direction along with everything it pushes, returns whether it moved.
+fn push(puzzle_map: &mut Grid<char>, location: &Coordinate, direction: &Direction) -> bool {
+  let is_vertical = [Direction::Up, Direction::Down].contains(direction);
+  let mut pushed = vec![location.clone()];
+  let mut seen = HashSet::from([location.clone()]);
+
+  let mut idx = 0;
+  while idx < pushed.len() {
+    let next = pushed[idx].add_delta(direction);
+    idx += 1;
+
+    let mut next_tiles = vec![];
+    match puzzle_map.get(&next) {
+      None | Some('#') => return false,
+      Some('O') => next_tiles.push(next),
+      Some('[') if is_vertical => next_tiles.extend([next.add_delta(&Direction::Right), next]),
+      Some(']') if is_vertical => next_tiles.extend([next.add_delta(&Direction::Left), next]),
+      Some('[' | ']') => next_tiles.push(next),
+      _ => {},
+    }
+
+    for tile in next_tiles {
+      if seen.insert(tile.clone()) {
+        pushed.push(tile);
+      }
+    }
+  }
+
+  let moved: Vec<char> = pushed.iter().map(|tile| std::mem::replace(&mut puzzle_map[tile], '.')).collect();
+  for (tile, location) in pushed.iter().zip(moved) {
+    puzzle_map[&tile.add_delta(direction)] = location;
+  }
+
+  true
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use utils::{ChallengeConfig, TEST_CONFIG};
+
+  #[test]
+  fn widens_everything_but_the_robot() {
+    let warehouse = parse_puzzle_input("#####\n#.O@#\n#####", "<").unwrap();
+
+    let wide_warehouse = widen(&warehouse);
+
+    assert_eq!(wide_warehouse.map.row(1).iter().collect::<String>(), "##..[]@.##");
+    assert_eq!(wide_warehouse.robot, Coordinate::new(1, 6));
+  }
+
+  #[test]
+  fn pushes_a_tree_of_wide_boxes() {
+    let config = ChallengeConfig { example: Some("smaller".to_string()), ..TEST_CONFIG };
+    let warehouse = config.load::<WarehouseWoes>().unwrap();
+
+    assert_eq!(sum_gps_coordinates(&widen(&warehouse)), 618);
+  }
+
+  #[test]
+  fn larger_example_sums_10092_and_9021_when_wide() {
+    let warehouse = TEST_CONFIG.load::<WarehouseWoes>().unwrap();
+
+    assert_eq!(sum_gps_coordinates(&warehouse), 10092);
+    assert_eq!(sum_gps_coordinates(&widen(&warehouse)), 9021);
+  }
+}
@@ -0,0 +1,117 @@
+/*
+ Advent of Code 2024 Day 16: Reindeer Maze
+
+ The reindeers compete in a maze to get the lowest score. The input is the map of the maze.
+ The walls are marked with a #, the start tile with an S and the end tile with an E.
+ The reindeers start facing east, moving forward one tile increases the score by 1 point and rotating 90 degrees clockwise or counterclockwise by 1000 points.
+
+ Part one:
+
+ What is the lowest score a reindeer could possibly get?
+
+ Part two:
+
+ How many tiles are part of at least one of the best paths through the maze?
+
+ Solution:
+
+ The state of the reindeer is not only its tile but also where it is facing, as turning is what makes paths expensive.
+ A Dijkstra search over those states finds the lowest score, and since it keeps every predecessor on a shortest path
+ the tiles of all the best paths are the ones of the states leading back to the start from the end.
+*/
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use utils::pathfinding::{dijkstra, Search};
+use utils::{find_marker, Coordinate, Direction, Grid, PuzzleInput, Result, Solution};
+
+pub struct ReindeerMaze;
+
+impl Solution for ReindeerMaze {
+  const DAY: u8 = 16;
+  const TITLE: &'static str = "Reindeer Maze";
+
+  type Input = Maze;
+
+  fn parse(input: &PuzzleInput) -> Result<Self::Input> {
+    parse_input(input)
+  }
+
+  fn part_one(maze: &Self::Input) -> impl Display {
+    lowest_score(maze).map_or_else(|| "No path to the end".to_string(), |score| score.to_string())
+  }
+
+  fn part_two(maze: &Self::Input) -> impl Display {
+    best_paths_tiles(maze)
+  }
+}
+
+/// The maze's walls and its start and end tiles.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Maze {
+  pub walls: Grid<bool>,
+  pub start: Coordinate,
+  pub end: Coordinate,
+}
+
+/// Where the reindeer is and where it is facing.
+pub type Reindeer = (Coordinate, Direction);
+
+pub fn parse_input(input: &str) -> Result<Maze> {
+  let (walls, markers) = Grid::parse(input.lines(), &['S', 'E'], |tile| tile == '#')?;
+
+  Ok(Maze { walls, start: find_marker(&markers, 'S')?, end: find_marker(&markers, 'E')? })
+}
+
+pub fn lowest_score(maze: &Maze) -> Option<usize> {
+  race(maze).distance()
+}
+
+pub fn best_paths_tiles(maze: &Maze) -> usize {
+  let tiles: HashSet<Coordinate> = race(maze).on_shortest_paths().into_iter().map(|(tile, _)| tile).collect();
+
+  tiles.len()
+}
+
+fn race(maze: &Maze) -> Search<Reindeer> {
+  dijkstra((maze.start.clone(), Direction::Right), |reindeer| moves(maze, reindeer), score, |(tile, _)| *tile == maze.end)
+}
+
+fn moves(maze: &Maze, (tile, facing): &Reindeer) -> Vec<Reindeer> {
+  let mut moves = vec![(tile.clone(), facing.rotate_right()), (tile.clone(), facing.rotate_left())];
+
+  let forward = tile.add_delta(facing);
+  if maze.walls.get(&forward) == Some(&false) {
+    moves.push((forward, facing.clone()));
+  }
+
+  moves
+}
+
+fn score((_, facing): &Reindeer, (_, next_facing): &Reindeer) -> usize {
+  if facing == next_facing { 1 } else { 1000 }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use utils::{ChallengeConfig, TEST_CONFIG};
+
+  #[test]
+  fn example_maze_lowest_score_is_7036() {
+    let maze = TEST_CONFIG.load::<ReindeerMaze>().unwrap();
+
+    assert_eq!(lowest_score(&maze), Some(7036));
+    assert_eq!(best_paths_tiles(&maze), 45);
+  }
+
+  #[test]
+  fn bigger_maze_lowest_score_is_11048() {
+    let config = ChallengeConfig { example: Some("bigger_maze".to_string()), ..TEST_CONFIG };
+    let maze = config.load::<ReindeerMaze>().unwrap();
+
+    assert_eq!(lowest_score(&maze), Some(11048));
+    assert_eq!(best_paths_tiles(&maze), 64);
+  }
+}
@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+
+use utils::{column_of, Error, Result};
+
+use super::instruction::{Combo, Instruction};
+use super::Register;
+
+/// Assembles a program written with mnemonics into the numbers the computer runs, one instruction per line, e.g. `adv 3` or `out b`.
+/// - Combo operands are 0 to 3 or a register, `a`, `b` or `c` in any case, or the numbers standing for them. 7 is reserved.
+/// - Literal operands are 0 to 7. `jnz` can also jump to a label, declared as `<name>:` alone or before an instruction.
+/// - `bxc` ignores its operand, so it can be left out.
+/// - Comments start with `;` or `#` and go to the end of the line.
+/// - Lines can start with the address of their instruction, as listed by `disassemble`.
+pub fn assemble(source: &str) -> Result<Vec<i32>> {
+  let mut labels = HashMap::new();
+  let mut statements = vec![];
+
+  for (idx, line) in source.lines().enumerate() {
+    let code = line.split([';', '#']).next().unwrap_or_default();
+    let mut tokens: Vec<&str> = code.split_whitespace().collect();
+    let error = |token: &str, cause: String| Error::parse(idx + 1, column_of(line, token), cause);
+
+    if let Some(label) = tokens.first().and_then(|token| token.strip_suffix(':')) {
+      let address = statements.len() * 2;
+      if labels.insert(label, address).is_some() {
+        return Err(error(tokens[0], format!("Duplicate label `{label}`")));
+      }
+      tokens.remove(0);
+    }
+
+    if tokens.len() > 1 && tokens[0].parse::<usize>().is_ok() {
+      let address = statements.len() * 2;
+      if tokens[0] != address.to_string() {
+        return Err(error(tokens[0], format!("Expected the instruction at {address}, found {}", tokens[0])));
+      }
+      tokens.remove(0);
+    }
+
+    match tokens[..] {
+      [] => {},
+      [mnemonic, ref operands @ ..] => {
+        let opcode = Instruction::MNEMONICS
+          .iter()
+          .position(|known| known.eq_ignore_ascii_case(mnemonic))
+          .ok_or_else(|| error(mnemonic, format!("Unknown instruction `{mnemonic}`")))?;
+
+        let operand = match operands {
+          [] if opcode == 4 => None,
+          [] => return Err(error(mnemonic, format!("Expected an operand for `{mnemonic}`"))),
+          [operand] => Some(*operand),
+          [_, unexpected, ..] => return Err(error(unexpected, format!("Unexpected `{unexpected}` after the operand"))),
+        };
+
+        statements.push((idx, line, opcode, operand));
+      },
+    }
+  }
+
+  let mut program = vec![];
+  for (idx, line, opcode, operand) in statements {
+    let error = |token: &str, cause: String| Error::parse(idx + 1, column_of(line, token), cause);
+
+    let instruction = match (opcode, operand) {
+      (4, None) => Instruction::Bxc(0),
+      (1 | 3 | 4, Some(operand)) => {
+        let literal = match (operand.parse::<u8>(), labels.get(operand)) {
+          (Ok(literal), _) if literal < 8 => literal,
+          (Ok(_), _) => return Err(error(operand, format!("Invalid literal operand `{operand}`, expected 0 to 7"))),
+          (_, Some(address)) if opcode == 3 && *address < 8 => *address as u8,
+          (_, Some(address)) if opcode == 3 => {
+            return Err(error(operand, format!("Label `{operand}` is at {address}, too far for a 3-bit jump")));
+          },
+          (_, _) if opcode == 3 => return Err(error(operand, format!("Unknown label `{operand}`"))),
+          (_, _) => return Err(error(operand, format!("Invalid literal operand `{operand}`, expected 0 to 7"))),
+        };
+
+        Instruction::decode(opcode as i32, literal as i32).unwrap()
+      },
+      (_, Some(operand)) => {
+        let combo = match operand.to_ascii_uppercase().as_str() {
+          "A" => Combo::Register(Register::A),
+          "B" => Combo::Register(Register::B),
+          "C" => Combo::Register(Register::C),
+          "7" => return Err(error(operand, "`7` is a reserved combo operand".to_string())),
+          number => number
+            .parse()
+            .ok()
+            .and_then(Combo::decode)
+            .ok_or_else(|| error(operand, format!("Invalid combo operand `{operand}`, expected 0 to 3 or a register")))?,
+        };
+
+        Instruction::decode(opcode as i32, combo.encode()).unwrap()
+      },
+      (_, None) => unreachable!("only bxc can leave its operand out"),
+    };
+
+    program.extend(instruction.encode());
+  }
+
+  Ok(program)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{disassemble, Computer};
+
+  #[test]
+  fn assembles_the_example_countdown() {
+    let source = "
+      ; halves A and outputs it until it's 0
+      loop:
+        adv 1      # A = A >> 1
+        out a
+        jnz loop
+    ";
+    let mut computer = Computer::new();
+    computer.set_register(Register::A, 2024);
+    computer.load_program(assemble(source).unwrap());
+
+    computer.run_program();
+
+    assert_eq!(computer.program(), [0, 1, 5, 4, 3, 0]);
+    assert_eq!(computer.flush(), "4,2,5,6,7,7,7,7,3,1,0");
+  }
+
+  #[test]
+  fn assembles_what_is_disassembled() {
+    let program = [2, 4, 1, 7, 7, 5, 0, 3, 1, 7, 4, 1, 5, 5, 3, 0];
+
+    assert_eq!(assemble(&disassemble(&program)).unwrap(), program);
+  }
+
+  #[test]
+  fn rejects_invalid_operands() {
+    let error = |source| assemble(source).unwrap_err().to_string();
+
+    assert_eq!(error("adv 7"), "Invalid input at line 1, column 5: `7` is a reserved combo operand");
+    assert_eq!(error("bst a\nbxl 8"), "Invalid input at line 2, column 5: Invalid literal operand `8`, expected 0 to 7");
+    assert_eq!(error("out d"), "Invalid input at line 1, column 5: Invalid combo operand `d`, expected 0 to 3 or a register");
+    assert_eq!(error("jnz end"), "Invalid input at line 1, column 5: Unknown label `end`");
+    assert_eq!(error("mul 2"), "Invalid input at line 1, column 1: Unknown instruction `mul`");
+  }
+}
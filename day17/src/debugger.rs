@@ -0,0 +1,390 @@
+use std::fmt::{self, Display};
+use std::io::{self, BufRead, Write};
+
+use super::disassembler::disassemble;
+use super::instruction::Instruction;
+use super::{Computer, Register};
+
+/// Values of the registers at some point of the program.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Registers {
+  pub a: u64,
+  pub b: u64,
+  pub c: u64,
+}
+
+impl Display for Registers {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "A={} B={} C={}", self.a, self.b, self.c)
+  }
+}
+
+/// An instruction that ran: where it was, the registers before and after it and what it output, if anything.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceEntry {
+  pub ip: usize,
+  pub opcode: i32,
+  pub operand: i32,
+  pub before: Registers,
+  pub after: Registers,
+  pub output: Option<i32>,
+}
+
+impl Display for TraceEntry {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let instruction = Instruction::decode(self.opcode, self.operand).map_or_else(|| format!("??? {},{}", self.opcode, self.operand), |instruction| instruction.to_string());
+    write!(f, "{:>4}  {instruction:<8} {} -> {}", self.ip, self.before, self.after)?;
+
+    match self.output {
+      Some(value) => write!(f, "  out {value}"),
+      None => Ok(()),
+    }
+  }
+}
+
+/// What happened when running one more instruction, see `Computer::step`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StepEvent {
+  Executed(TraceEntry),
+  /// The instruction pointer is past the end of the program, so nothing ran.
+  Halted,
+  /// The numbers at the instruction pointer aren't a valid instruction, e.g. a combo operand of 7, so nothing ran.
+  Invalid { ip: usize, opcode: i32, operand: i32 },
+}
+
+/// Where the debugger stops the program: before running the instruction at an address, or after outputting any or some value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Breakpoint {
+  Address(usize),
+  Output,
+  OutputValue(i32),
+}
+
+impl Display for Breakpoint {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Self::Address(ip) => write!(f, "at {ip}"),
+      Self::Output => write!(f, "on output"),
+      Self::OutputValue(value) => write!(f, "on output {value}"),
+    }
+  }
+}
+
+/// Why the debugger stopped running the program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+  Breakpoint(Breakpoint),
+  Halted,
+  /// The program reached an invalid instruction, see `StepEvent::Invalid`.
+  Invalid { ip: usize, opcode: i32, operand: i32 },
+}
+
+/// Runs a computer's program step by step or until a breakpoint, recording what each instruction did when tracing.
+#[derive(Debug, Clone)]
+pub struct Debugger {
+  initial: Computer,
+  computer: Computer,
+  breakpoints: Vec<Breakpoint>,
+  trace: Option<Vec<TraceEntry>>,
+}
+
+impl Debugger {
+  pub fn new(computer: Computer) -> Self {
+    Self { initial: computer.clone(), computer, breakpoints: vec![], trace: None }
+  }
+
+  pub fn computer(&self) -> &Computer {
+    &self.computer
+  }
+
+  pub fn computer_mut(&mut self) -> &mut Computer {
+    &mut self.computer
+  }
+
+  pub fn breakpoints(&self) -> &[Breakpoint] {
+    &self.breakpoints
+  }
+
+  pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) {
+    if !self.breakpoints.contains(&breakpoint) {
+      self.breakpoints.push(breakpoint);
+    }
+  }
+
+  /// Removes a breakpoint, returns whether there was one.
+  pub fn remove_breakpoint(&mut self, breakpoint: Breakpoint) -> bool {
+    let count = self.breakpoints.len();
+    self.breakpoints.retain(|known| *known != breakpoint);
+
+    self.breakpoints.len() < count
+  }
+
+  /// Starts or stops recording the instructions that run. Stopping clears the trace.
+  pub fn set_tracing(&mut self, tracing: bool) {
+    self.trace = if tracing { Some(self.trace.take().unwrap_or_default()) } else { None };
+  }
+
+  pub fn is_tracing(&self) -> bool {
+    self.trace.is_some()
+  }
+
+  /// The instructions that ran while tracing, oldest first.
+  pub fn trace(&self) -> &[TraceEntry] {
+    self.trace.as_deref().unwrap_or_default()
+  }
+
+  /// Runs the next instruction.
+  pub fn step(&mut self) -> StepEvent {
+    let event = self.computer.step();
+    if let (Some(trace), StepEvent::Executed(entry)) = (&mut self.trace, &event) {
+      trace.push(entry.clone());
+    }
+
+    event
+  }
+
+  /// Runs the program until it halts, reaches a breakpoint or an invalid instruction. It always runs at least one instruction,
+  /// so resuming from an address breakpoint goes past it.
+  pub fn resume(&mut self) -> Stop {
+    loop {
+      let entry = match self.step() {
+        StepEvent::Executed(entry) => entry,
+        StepEvent::Halted => return Stop::Halted,
+        StepEvent::Invalid { ip, opcode, operand } => return Stop::Invalid { ip, opcode, operand },
+      };
+
+      let hit = self.breakpoints.iter().find(|breakpoint| match breakpoint {
+        Breakpoint::Address(ip) => *ip == self.computer.ip(),
+        Breakpoint::Output => entry.output.is_some(),
+        Breakpoint::OutputValue(value) => entry.output == Some(*value),
+      });
+      if let Some(breakpoint) = hit {
+        return Stop::Breakpoint(*breakpoint);
+      }
+    }
+  }
+
+  /// Goes back to the computer as it was given, keeping the breakpoints and clearing the trace.
+  pub fn restart(&mut self) {
+    self.computer = self.initial.clone();
+    if self.is_tracing() {
+      self.trace = Some(vec![]);
+    }
+  }
+}
+
+/// Commands of the interactive debugger.
+pub const REPL_USAGE: &str = "\
+Commands:
+  s, step [n]           Runs the next instruction, or the next n ones.
+  c, continue           Runs until a breakpoint or the end of the program.
+  b, break <ip>         Stops before running the instruction at the address.
+  b, break out [value]  Stops after outputting any value, or the given one.
+  d, delete <ip|out [value]>
+                        Removes a breakpoint.
+  r, registers          Prints the registers, instruction pointer and output.
+  set <a|b|c> <value>   Changes a register.
+  l, list               Prints the program, marking the next instruction.
+  t, trace [on|off]     Prints the instructions recorded while tracing, or starts or stops tracing.
+  restart               Goes back to the start of the program with the initial registers.
+  h, help               Prints this help.
+  q, quit               Leaves the debugger.";
+
+/// Debugs a program interactively, reading commands from the input until it ends or `quit`. See `REPL_USAGE`.
+pub fn repl(debugger: &mut Debugger, input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+  writeln!(output, "Debugging a {} numbers long program, `help` lists the commands.", debugger.computer().program().len())?;
+  write!(output, "(day17) ")?;
+  output.flush()?;
+
+  for line in input.lines() {
+    let line = line?;
+    let words: Vec<&str> = line.split_whitespace().collect();
+
+    match words[..] {
+      [] => {},
+      ["q" | "quit"] => return Ok(()),
+      ["h" | "help"] => writeln!(output, "{REPL_USAGE}")?,
+      ["s" | "step"] => {
+        print_step(debugger, &mut output)?;
+      },
+      ["s" | "step", steps] => match steps.parse::<usize>() {
+        Ok(steps) => {
+          for _ in 0..steps {
+            if !print_step(debugger, &mut output)? {
+              break;
+            }
+          }
+        },
+        Err(_) => writeln!(output, "Invalid number of steps `{steps}`")?,
+      },
+      ["c" | "continue"] => match debugger.resume() {
+        Stop::Halted => writeln!(output, "Halted, output: {}", debugger.computer().flush())?,
+        Stop::Breakpoint(breakpoint) => {
+          writeln!(output, "Stopped {breakpoint}, next instruction at {}", debugger.computer().ip())?;
+        },
+        Stop::Invalid { ip, opcode, operand } => print_invalid(ip, opcode, operand, &mut output)?,
+      },
+      ["b" | "break", ref target @ ..] => match parse_breakpoint(target) {
+        Some(breakpoint) => {
+          debugger.add_breakpoint(breakpoint);
+          writeln!(output, "Breakpoint {breakpoint}")?;
+        },
+        None => writeln!(output, "Expected an address, `out` or `out <value>`")?,
+      },
+      ["d" | "delete", ref target @ ..] => match parse_breakpoint(target) {
+        Some(breakpoint) if debugger.remove_breakpoint(breakpoint) => writeln!(output, "Removed breakpoint {breakpoint}")?,
+        Some(breakpoint) => writeln!(output, "No breakpoint {breakpoint}")?,
+        None => writeln!(output, "Expected an address, `out` or `out <value>`")?,
+      },
+      ["r" | "registers"] => {
+        let computer = debugger.computer();
+        writeln!(output, "{}  ip={}  output: {}", computer.registers(), computer.ip(), computer.flush())?;
+      },
+      ["set", register, value] => {
+        let register = match register.to_ascii_uppercase().as_str() {
+          "A" => Some(Register::A),
+          "B" => Some(Register::B),
+          "C" => Some(Register::C),
+          _ => None,
+        };
+        match (register, value.parse()) {
+          (Some(register), Ok(value)) => debugger.computer_mut().set_register(register, value),
+          _ => writeln!(output, "Expected `set <a|b|c> <value>`")?,
+        }
+      },
+      ["l" | "list"] => {
+        let ip = debugger.computer().ip();
+        for line in disassemble(debugger.computer().program()).lines() {
+          let is_next = line.split_whitespace().next().and_then(|address| address.parse().ok()) == Some(ip);
+          writeln!(output, "{} {line}", if is_next { ">" } else { " " })?;
+        }
+      },
+      ["t" | "trace"] => {
+        for entry in debugger.trace() {
+          writeln!(output, "{entry}")?;
+        }
+        if !debugger.is_tracing() {
+          writeln!(output, "Not tracing, start with `trace on`")?;
+        }
+      },
+      ["t" | "trace", "on"] => debugger.set_tracing(true),
+      ["t" | "trace", "off"] => debugger.set_tracing(false),
+      ["restart"] => debugger.restart(),
+      _ => writeln!(output, "Unknown command `{line}`, `help` lists them")?,
+    }
+
+    write!(output, "(day17) ")?;
+    output.flush()?;
+  }
+
+  Ok(())
+}
+
+// Prints the instruction that ran, returns whether one did.
+fn print_step(debugger: &mut Debugger, output: &mut impl Write) -> io::Result<bool> {
+  match debugger.step() {
+    StepEvent::Executed(entry) => writeln!(output, "{entry}").map(|_| true),
+    StepEvent::Halted => writeln!(output, "Halted, output: {}", debugger.computer().flush()).map(|_| false),
+    StepEvent::Invalid { ip, opcode, operand } => print_invalid(ip, opcode, operand, output).map(|_| false),
+  }
+}
+
+fn print_invalid(ip: usize, opcode: i32, operand: i32, output: &mut impl Write) -> io::Result<()> {
+  writeln!(output, "Invalid instruction `{opcode},{operand}` at {ip}, the program can't go on")
+}
+
+fn parse_breakpoint(words: &[&str]) -> Option<Breakpoint> {
+  match words {
+    ["out"] => Some(Breakpoint::Output),
+    ["out", value] => value.parse().ok().map(Breakpoint::OutputValue),
+    [ip] => ip.parse().ok().map(Breakpoint::Address),
+    _ => None,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::assemble;
+
+  fn countdown() -> Debugger {
+    let mut computer = Computer::new();
+    computer.set_register(Register::A, 2024);
+    computer.load_program(assemble("loop: adv 1\nout a\njnz loop").unwrap());
+
+    Debugger::new(computer)
+  }
+
+  #[test]
+  fn traces_each_step() {
+    let mut debugger = countdown();
+    debugger.set_tracing(true);
+
+    debugger.step();
+    let StepEvent::Executed(entry) = debugger.step() else { panic!("The program halted") };
+
+    assert_eq!(entry.ip, 2);
+    assert_eq!(entry.before, Registers { a: 1012, b: 0, c: 0 });
+    assert_eq!(entry.output, Some(4));
+    assert_eq!(debugger.trace().len(), 2);
+    assert_eq!(debugger.trace()[0].after.a, 1012);
+  }
+
+  #[test]
+  fn stops_at_breakpoints() {
+    let mut debugger = countdown();
+    debugger.add_breakpoint(Breakpoint::OutputValue(7));
+    debugger.add_breakpoint(Breakpoint::Address(4));
+
+    assert_eq!(debugger.resume(), Stop::Breakpoint(Breakpoint::Address(4)));
+    assert!(debugger.remove_breakpoint(Breakpoint::Address(4)));
+    assert_eq!(debugger.resume(), Stop::Breakpoint(Breakpoint::OutputValue(7)));
+    assert_eq!(debugger.computer().flush(), "4,2,5,6,7");
+    assert_eq!(debugger.resume(), Stop::Breakpoint(Breakpoint::OutputValue(7)));
+    debugger.remove_breakpoint(Breakpoint::OutputValue(7));
+    assert_eq!(debugger.resume(), Stop::Halted);
+    assert_eq!(debugger.computer().flush(), "4,2,5,6,7,7,7,7,3,1,0");
+  }
+
+  #[test]
+  fn stops_on_invalid_instructions() {
+    for program in [vec![0, 7], vec![1, 2, 8, 0]] {
+      let mut computer = Computer::new();
+      computer.load_program(program.clone());
+      let mut debugger = Debugger::new(computer);
+
+      let ip = program.len() - 2;
+      let invalid = Stop::Invalid { ip, opcode: program[ip], operand: program[ip + 1] };
+      assert_eq!(debugger.resume(), invalid, "{program:?}");
+      assert_eq!(debugger.computer().ip(), ip);
+    }
+  }
+
+  #[test]
+  fn shifts_everything_out_past_63_bits() {
+    let mut computer = Computer::new();
+    computer.set_register(Register::A, u64::MAX);
+    computer.load_program(assemble("cdv b\nbdv b").unwrap());
+    let mut debugger = Debugger::new(computer);
+    let mut output = vec![];
+
+    repl(&mut debugger, "set b 100\nstep\nset b 4294967296\nstep".as_bytes(), &mut output).unwrap();
+
+    assert_eq!(debugger.computer().registers(), Registers { a: u64::MAX, b: 0, c: 0 });
+  }
+
+  #[test]
+  fn runs_commands_until_quitting() {
+    let mut debugger = countdown();
+    let mut output = vec![];
+
+    repl(&mut debugger, "b out\ncontinue\nr\nstep 2\nq\nstep".as_bytes(), &mut output).unwrap();
+
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.contains("Breakpoint on output"));
+    assert!(output.contains("Stopped on output, next instruction at 4"));
+    assert!(output.contains("A=1012 B=0 C=0  ip=4  output: 4"));
+    assert!(output.contains("   0  adv 1    A=1012 B=0 C=0 -> A=506 B=0 C=0"));
+    assert_eq!(debugger.computer().flush(), "4");
+  }
+}
@@ -0,0 +1,79 @@
+use std::collections::BTreeSet;
+
+use super::instruction::Instruction;
+
+/// Renders the program one instruction per line after its address, e.g. `adv 3` or `out B`, with labels on the jump targets.
+/// Pairs of numbers that aren't a valid instruction are shown as `??? <opcode>,<operand>`.
+pub fn disassemble(program: &[i32]) -> String {
+  render(program, |instruction, target| match instruction {
+    Instruction::Jnz(_) => format!("jnz {target}"),
+    instruction => instruction.to_string(),
+  })
+}
+
+/// Renders the program like `disassemble` but with what each instruction does, e.g. `B = A % 8`.
+pub fn pseudo_code(program: &[i32]) -> String {
+  render(program, |instruction, target| instruction.pseudo_code(target))
+}
+
+// The jump targets that are the start of an instruction get a label, others are left as a number.
+fn render(program: &[i32], line: impl Fn(&Instruction, &str) -> String) -> String {
+  let labels: BTreeSet<usize> = program
+    .chunks(2)
+    .filter_map(|pair| match pair {
+      [3, target] if target % 2 == 0 && (*target as usize) < program.len() => Some(*target as usize),
+      _ => None,
+    })
+    .collect();
+
+  let mut lines = vec![];
+  for (idx, pair) in program.chunks(2).enumerate() {
+    let ip = idx * 2;
+    if labels.contains(&ip) {
+      lines.push(format!("L{ip}:"));
+    }
+
+    let text = match pair {
+      [opcode, operand] => match Instruction::decode(*opcode, *operand) {
+        Some(instruction) => {
+          let target = if labels.contains(&(*operand as usize)) { format!("L{operand}") } else { operand.to_string() };
+          line(&instruction, &target)
+        },
+        None => format!("??? {opcode},{operand}"),
+      },
+      [opcode] => format!("??? {opcode}"),
+      _ => unreachable!(),
+    };
+    lines.push(format!("{ip:>4}  {text}"));
+  }
+
+  lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const PROGRAM: [i32; 16] = [2, 4, 1, 7, 7, 5, 0, 3, 1, 7, 4, 1, 5, 5, 3, 0];
+
+  #[test]
+  fn names_the_registers_and_labels_the_jumps() {
+    let listing = ["L0:", "   0  bst A", "   2  bxl 7", "   4  cdv B", "   6  adv 3", "   8  bxl 7", "  10  bxc 1", "  12  out B", "  14  jnz L0"];
+
+    assert_eq!(disassemble(&PROGRAM), listing.join("\n"));
+  }
+
+  #[test]
+  fn describes_what_each_instruction_does() {
+    let code = pseudo_code(&PROGRAM);
+
+    assert_eq!(code.lines().nth(1), Some("   0  B = A % 8"));
+    assert_eq!(code.lines().nth(4), Some("   6  A = A >> 3"));
+    assert_eq!(code.lines().last(), Some("  14  if A != 0 goto L0"));
+  }
+
+  #[test]
+  fn shows_what_isnt_an_instruction() {
+    assert_eq!(disassemble(&[0, 7, 3, 3, 5]), ["   0  ??? 0,7", "   2  jnz 3", "   4  ??? 5"].join("\n"));
+  }
+}
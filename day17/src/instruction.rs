@@ -0,0 +1,118 @@
+use std::fmt::{self, Display};
+
+use super::Register;
+
+/// Operand of the instructions reading a combo operand: a literal value up to 3 or one of the registers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Combo {
+  Literal(u8),
+  Register(Register),
+}
+
+impl Combo {
+  /// Decodes a combo operand, 7 being reserved.
+  pub fn decode(operand: i32) -> Option<Self> {
+    match operand {
+      0..=3 => Some(Self::Literal(operand as u8)),
+      4 => Some(Self::Register(Register::A)),
+      5 => Some(Self::Register(Register::B)),
+      6 => Some(Self::Register(Register::C)),
+      _ => None,
+    }
+  }
+
+  pub fn encode(&self) -> i32 {
+    match self {
+      Self::Literal(value) => *value as i32,
+      Self::Register(Register::A) => 4,
+      Self::Register(Register::B) => 5,
+      Self::Register(Register::C) => 6,
+    }
+  }
+}
+
+impl Display for Combo {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Self::Literal(value) => write!(f, "{value}"),
+      Self::Register(register) => write!(f, "{register:?}"),
+    }
+  }
+}
+
+/// An instruction of the 3-bit computer with its operand. `bxc` ignores its operand but keeps it so the program is the same once encoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+  Adv(Combo),
+  Bxl(u8),
+  Bst(Combo),
+  Jnz(u8),
+  Bxc(u8),
+  Out(Combo),
+  Bdv(Combo),
+  Cdv(Combo),
+}
+
+impl Instruction {
+  /// Mnemonics of the instructions, by opcode.
+  pub const MNEMONICS: [&'static str; 8] = ["adv", "bxl", "bst", "jnz", "bxc", "out", "bdv", "cdv"];
+
+  /// Decodes an opcode and its operand, unless they aren't 3-bit numbers or the combo operand is the reserved 7.
+  pub fn decode(opcode: i32, operand: i32) -> Option<Self> {
+    let literal = u8::try_from(operand).ok().filter(|operand| *operand < 8)?;
+    let combo = Combo::decode(operand);
+
+    match opcode {
+      0 => combo.map(Self::Adv),
+      1 => Some(Self::Bxl(literal)),
+      2 => combo.map(Self::Bst),
+      3 => Some(Self::Jnz(literal)),
+      4 => Some(Self::Bxc(literal)),
+      5 => combo.map(Self::Out),
+      6 => combo.map(Self::Bdv),
+      7 => combo.map(Self::Cdv),
+      _ => None,
+    }
+  }
+
+  /// Returns the opcode and operand of the instruction.
+  pub fn encode(&self) -> [i32; 2] {
+    match self {
+      Self::Adv(combo) => [0, combo.encode()],
+      Self::Bxl(literal) => [1, *literal as i32],
+      Self::Bst(combo) => [2, combo.encode()],
+      Self::Jnz(literal) => [3, *literal as i32],
+      Self::Bxc(literal) => [4, *literal as i32],
+      Self::Out(combo) => [5, combo.encode()],
+      Self::Bdv(combo) => [6, combo.encode()],
+      Self::Cdv(combo) => [7, combo.encode()],
+    }
+  }
+
+  pub fn mnemonic(&self) -> &'static str {
+    Self::MNEMONICS[self.encode()[0] as usize]
+  }
+
+  /// What the instruction does, e.g. `B = A % 8`, with the given name for the jump target.
+  pub fn pseudo_code(&self, target: &str) -> String {
+    match self {
+      Self::Adv(combo) => format!("A = A >> {combo}"),
+      Self::Bxl(literal) => format!("B = B ^ {literal}"),
+      Self::Bst(combo) => format!("B = {combo} % 8"),
+      Self::Jnz(_) => format!("if A != 0 goto {target}"),
+      Self::Bxc(_) => "B = B ^ C".to_string(),
+      Self::Out(combo) => format!("out {combo} % 8"),
+      Self::Bdv(combo) => format!("B = A >> {combo}"),
+      Self::Cdv(combo) => format!("C = A >> {combo}"),
+    }
+  }
+}
+
+impl Display for Instruction {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Self::Adv(combo) | Self::Bst(combo) | Self::Out(combo) | Self::Bdv(combo) | Self::Cdv(combo) => write!(f, "{} {combo}", self.mnemonic()),
+      Self::Bxl(literal) | Self::Jnz(literal) | Self::Bxc(literal) => write!(f, "{} {literal}", self.mnemonic()),
+    }
+  }
+}
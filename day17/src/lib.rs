@@ -0,0 +1,488 @@
+/*
+ Advent of Code 2024 Day 17: Chronospatial Computer
+
+ I'm in a 3-bit computer. It has three registers A,B,C, these can contain any integers.
+ The computer knows 8 instructions, each identified by a 3 bit number called opcode.
+ Each instruction reads a 3 bit number after it as an input, called operand.
+ A number called the instrucction pointer identiifies the position in the program.
+ the instruction pointer increases by 2 after each instruction is processed (except for jump instructions)
+ if the computer tries to read an opcode past the end of the program, it halts.
+ There are two types of operands,each instruction specifies the type of its operand.
+ The value of a literal operand is the operand itself, e.g. 7 is just the number 7. 
+ The value of a combo operand is:
+  - 0 to 3: a literal value.
+  - 4 is the value of register A
+  - 5 the value of register B
+  - 6 teh value of register C
+  - 7 is reserved and will not appear in a valid program.
+
+  These are the instructions:
+  - adv (opcode 0): performs division. The numerator is the A register the denominator is 2 raised to a combo operand. The divsion is trucnated and stored in A
+  - bxl (opcode 1): bitwise XOR of register B and a literal operand. Stores the result in B
+  - bst (opcode 2): value of combo operand modulo 8 the nwrites to the B register.
+  - jnz (opcode 3): nothing if the register A is 0, otherwise jumps by setting the instruction pointer to hte value of the literal operand.
+  - bxc (opcode 4): bitwise XOR of register B and register C. store the result in register B. The operand is ignored.
+  - out (opcode 5): result of combo operand modulo 8, then outputs that value (values are outputed separated by commas)
+  - bdv (opcode 6): same as adv but sotring the result in B register.
+  - cdv (opcode 7): same as adv  but result is storedin C register.
+  
+ Part one:
+ 
+ What do I get if I use commas to join the values the program outputs into a single string?
+
+ Part two:
+
+ What is the lowest possible initial value for register A that causes the program to output a copy of itself?
+
+ Solution:
+
+ for part one I just needed to setup the operations and a computer and run the program. The part two was tricky.
+ Brute forcing for part two was not possible, so seeing other solutions and thinking about it it was clear that the number of A is just just the lowest possible 
+ between numbers that are increasing in powers of 8 fashion, that is shifting 3 times. The best way is to reverse the calculation starting from hte last printed value
+ collect all possible A values that would produce that and then shift each of them 3 times and see the next 7 possible values.
+ That makes it super fast.
+
+*/
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use utils::{column_of, parse_field, Error, PuzzleInput, Result, Solution};
+
+mod assembler;
+mod debugger;
+mod disassembler;
+mod instruction;
+
+pub use assembler::assemble;
+pub use debugger::{repl, Breakpoint, Debugger, Registers, StepEvent, Stop, TraceEntry, REPL_USAGE};
+pub use disassembler::{disassemble, pseudo_code};
+pub use instruction::{Combo, Instruction};
+
+pub struct ChronospatialComputer;
+
+impl Solution for ChronospatialComputer {
+  const DAY: u8 = 17;
+  const TITLE: &'static str = "Chronospatial Computer";
+
+  type Input = Computer;
+
+  fn parse(input: &PuzzleInput) -> Result<Self::Input> {
+    parse_input(input)
+  }
+
+  fn part_one(computer: &Self::Input) -> impl Display {
+    let mut computer = computer.clone();
+    computer.run_program();
+
+    computer.flush()
+  }
+
+  fn part_two(computer: &Self::Input) -> impl Display {
+    computer.clone().run_until_copy()
+  }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash,)]
+pub enum Register {
+  A, B, C
+}
+
+/// The 3-bit computer, with its registers, the program loaded and what it has output so far.
+#[derive(Debug, Clone)]
+pub struct Computer {
+  registers: HashMap<Register, u64>,
+  ip: usize,
+  output_buffer: Vec<i32>,
+  program: Vec<i32>,
+}
+
+impl Default for Computer {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl Computer {
+  /// Builds a computer with every register at 0 and no program.
+  pub fn new() -> Self {
+    let mut registers = HashMap::new();
+    registers.insert(Register::A, 0);
+    registers.insert(Register::B, 0);
+    registers.insert(Register::C, 0);
+
+    Self {
+      registers,
+      output_buffer: vec![],
+      ip: 0,
+      program: vec![],
+    }
+  }
+
+  pub fn register(&self, register: Register) -> u64 {
+    self.registers[&register]
+  }
+
+  pub fn set_register(&mut self, register: Register, value: u64) {
+    self.registers.insert(register, value);
+  }
+
+  /// Values of all the registers.
+  pub fn registers(&self) -> Registers {
+    Registers { a: self.registers[&Register::A], b: self.registers[&Register::B], c: self.registers[&Register::C] }
+  }
+
+  /// Address of the next instruction to run.
+  pub fn ip(&self) -> usize {
+    self.ip
+  }
+
+  pub fn program(&self) -> &[i32] {
+    &self.program
+  }
+
+  /// Loads a program to run from its start.
+  pub fn load_program(&mut self, program: Vec<i32>) {
+    self.program = program;
+    self.ip = 0;
+  }
+
+  /// Returns the values output so far.
+  pub fn output(&self) -> &[i32] {
+    &self.output_buffer
+  }
+
+  /// Returns the values output so far joined by commas.
+  pub fn flush(&self) -> String {
+    let numbers: Vec<String> = self.output_buffer.iter().map(|n| n.to_string()).collect();
+    numbers.join(",")
+  }
+
+  /// Runs the program until it halts or reaches an invalid instruction.
+  pub fn run_program(&mut self) {
+    while let StepEvent::Executed(_) = self.step() {}
+  }
+
+  /// Runs the next instruction, telling what it did, see `Debugger` to also record it or stop at breakpoints.
+  /// The computer stays on an invalid instruction, e.g. the reserved combo operand 7, without running it.
+  pub fn step(&mut self) -> StepEvent {
+    // if the instruction pointer is past the end of the program, or there's no operand left, halt.
+    if self.ip + 1 >= self.program.len() {
+      return StepEvent::Halted;
+    }
+
+    // get next opcode and operand
+    let (ip, opcode, operand) = (self.ip, self.program[self.ip], self.program[self.ip + 1]);
+    let Some(instruction) = Instruction::decode(opcode, operand) else {
+      return StepEvent::Invalid { ip, opcode, operand };
+    };
+    let before = self.registers();
+    let output_len = self.output_buffer.len();
+
+    // do operation and sets the next instruction pointer
+    self.operate(instruction);
+
+    StepEvent::Executed(TraceEntry { ip, opcode, operand, before, after: self.registers(), output: self.output_buffer.get(output_len).copied() })
+  }
+
+  fn operate(&mut self, instruction: Instruction) {
+    match instruction {
+      Instruction::Adv(combo) => {
+        let operand = self.decombize(combo);
+
+        self
+          .registers
+          .entry(Register::A)
+          .and_modify(|register| *register = divide(*register, operand));
+
+        self.ip += 2;
+      },
+      Instruction::Bxl(literal) => {
+        self
+          .registers
+          .entry(Register::B)
+          .and_modify(|register| *register ^= literal as u64);
+
+        self.ip += 2;
+      },
+      Instruction::Bst(combo) => {
+        let operand = self.decombize(combo);
+
+        self
+          .registers
+          .insert(Register::B, operand.rem_euclid(8));
+      
+        self.ip += 2;
+      },
+      Instruction::Jnz(literal) => {
+        self.ip = if self.registers[&Register::A] != 0 { 
+          literal as usize 
+        } else {
+          self.ip + 2
+        }
+      },
+      // the operand is ignored
+      Instruction::Bxc(_) => {
+        let operand = self.registers[&Register::C];
+          
+        self
+          .registers
+          .entry(Register::B)
+          .and_modify(|register| *register ^= operand);
+        
+        self.ip += 2;
+      },
+      Instruction::Out(combo) => {
+        let operand = self.decombize(combo);
+
+        self.output_buffer.push(operand.rem_euclid(8) as i32);
+        self.ip += 2;
+      }
+      Instruction::Bdv(combo) => {
+        let (operator, operand) = (self.registers[&Register::A], self.decombize(combo));
+
+        self
+          .registers
+          .insert(Register::B, divide(operator, operand));
+      
+        self.ip += 2;
+      },
+      Instruction::Cdv(combo) => {
+        let (operator, operand) = (self.registers[&Register::A], self.decombize(combo));
+
+        self
+          .registers
+          .insert(Register::C, divide(operator, operand));
+        
+        self.ip += 2;
+      },
+    }; 
+  }
+
+  fn decombize(&self, combo: Combo) -> u64 {
+    match combo {
+      Combo::Literal(value) => value as u64,
+      Combo::Register(register) => self.registers[&register],
+    }
+  }
+
+  /// Clears the registers, the output and goes back to the start of the program.
+  pub fn reset(&mut self) {
+    self.ip = 0;
+    self.output_buffer = vec![];
+    self.registers.insert(Register::A,0);
+    self.registers.insert(Register::B,0);
+    self.registers.insert(Register::C,0);
+  }
+
+    // Returns the value of register A that satisfies the output buffer to be equal to the program condition
+    // This version is about going from the end to the beginning;
+    pub fn run_until_copy(&mut self) -> u64 {
+      let pattern = [0b000, 0b001, 0b010, 0b011, 0b100, 0b101, 0b110, 0b111].to_vec();
+      let mut possible_numbers = pattern.clone();
+      let mut compared = 0;
+  
+      loop {
+        let mut successful = Vec::new();
+
+        for next_number in &possible_numbers {
+          if compared == 0 && *next_number == 0 {
+            compared += 1;
+            continue;
+          }
+          self.reset();
+          self.registers.insert(Register::A,*next_number);
+          self.run_program();
+          
+          // println!("next_number: {next_number:o}");
+          // println!("buf: {:?}", self.output_buffer);
+
+          let (_, last) = self.program.split_at(self.program.len() - self.output_buffer.len());
+          if self.output_buffer == last {
+            successful.push(next_number);
+          }
+        }
+        
+        if self.output_buffer.len() == self.program.len() {
+          return **successful.iter().min().unwrap();
+        }
+
+        let mut next_possible_numbers = vec![];
+        for pos_n in &pattern {
+          for suc_n in &successful {
+            next_possible_numbers.push((*suc_n << 3 ) + pos_n);
+          }
+        }
+        possible_numbers = next_possible_numbers.clone();
+        // println!("next pos n: {:?}", possible_numbers.iter().map(|n| format!("{:o}", n)).collect::<Vec<String>>());
+      }
+    }
+}
+
+// Divides by 2 raised to the power, which is shifting right. Powers of 64 or more leave nothing.
+fn divide(numerator: u64, power: u64) -> u64 {
+  u32::try_from(power).ok().and_then(|power| numerator.checked_shr(power)).unwrap_or(0)
+}
+
+/// Reads the registers and the program, which has to be made of valid instructions.
+pub fn parse_input(input: &str) -> Result<Computer> {
+  let mut computer = Computer::new();
+  // the registers and the program are each on their own line, blank lines are skipped
+  let mut lines = input.lines().enumerate().filter(|(_, line)| !line.trim().is_empty());
+  let end = input.lines().count() + 1;
+
+  for register in [Register::A, Register::B, Register::C] {
+    let (line_number, line, value) = expect_line(lines.next(), end, &format!("Register {register:?}: "), "<n>")?;
+    computer.registers.insert(register, parse_field(line, line_number, value)?);
+  }
+
+  let (line_number, line, numbers) = expect_line(lines.next(), end, "Program: ", "<n>,<n>,...")?;
+  let numbers: Vec<&str> = numbers.split(',').map(|number| number.trim()).collect();
+  computer.program = numbers.iter().map(|number| parse_field(line, line_number, number)).collect::<Result<_>>()?;
+
+  if let Err((idx, cause)) = check_program(&computer.program) {
+    return Err(Error::parse(line_number, column_of(line, numbers[idx]), cause));
+  }
+
+  Ok(computer)
+}
+
+// Returns the line number, the line and what follows the prefix, or where the expected line is missing.
+fn expect_line<'a>(line: Option<(usize, &'a str)>, end: usize, prefix: &str, value: &str) -> Result<(usize, &'a str, &'a str)> {
+  match line {
+    Some((idx, line)) => line
+      .strip_prefix(prefix)
+      .map(|rest| (idx + 1, line, rest.trim()))
+      .ok_or_else(|| Error::parse(idx + 1, 1, format!("Expected `{prefix}{value}`, found `{line}`"))),
+    None => Err(Error::parse(end, 1, format!("Expected `{prefix}{value}`"))),
+  }
+}
+
+// Returns the index of the first number that isn't part of a valid instruction, and why.
+fn check_program(program: &[i32]) -> std::result::Result<(), (usize, String)> {
+  for (idx, pair) in program.chunks(2).enumerate() {
+    let ip = idx * 2;
+    if let Some(offset) = pair.iter().position(|number| !(0..8).contains(number)) {
+      return Err((ip + offset, format!("`{}` at index {} isn't a 3-bit number", pair[offset], ip + offset)));
+    }
+
+    // adv, bst, out, bdv and cdv read a combo operand
+    if let [opcode @ (0 | 2 | 5 | 6 | 7), 7] = pair {
+      return Err((ip + 1, format!("Opcode {opcode} at index {ip} uses the reserved combo operand 7")));
+    }
+  }
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn example_1() {
+    let mut computer = Computer::new();
+    computer.registers.insert(Register::C,  9);
+    computer.program = [2,6].to_vec();
+
+    computer.run_program();
+    
+    assert_eq!(computer.registers[&Register::B], 1);
+  }
+
+  #[test]
+  fn example_2() {
+    let mut computer = Computer::new();
+    computer.registers.insert(Register::A,  10);
+    computer.program = [5,0,5,1,5,4].to_vec();
+
+    computer.run_program();
+
+    assert_eq!("0,1,2", computer.flush());
+  }
+
+  #[test]
+  fn example_3() {
+    let mut computer = Computer::new();
+    computer.registers.insert(Register::A,  2024);
+    computer.program = [0,1,5,4,3,0].to_vec();
+    
+    computer.run_program();
+
+    assert_eq!("4,2,5,6,7,7,7,7,3,1,0", computer.flush());
+    assert_eq!(computer.registers[&Register::A], 0);
+  }
+
+
+  #[test]
+  fn example_4() {
+    let mut computer = Computer::new();
+    computer.registers.insert(Register::B,  29);
+    computer.program = [1,7].to_vec();
+    
+    computer.run_program();
+    
+    assert_eq!(computer.registers[&Register::B], 26);
+  }
+
+
+  #[test]
+  fn example_5() {
+    let mut computer = Computer::new();
+    computer.registers.insert(Register::B,  2024);
+    computer.registers.insert(Register::C,  43690);
+    computer.program = [4,0].to_vec();
+    
+    computer.run_program();
+    
+    assert_eq!(computer.registers[&Register::B], 44354);
+  }
+
+  #[test]
+  fn example_input_produces_correct_output() {
+    let mut computer = Computer::new();
+    computer.registers.insert(Register::A,  729);
+    computer.registers.insert(Register::B,  0);
+    computer.registers.insert(Register::C,  0);
+    computer.program = [0, 1, 5, 4, 3, 0].to_vec();
+
+    computer.run_program();    
+
+    assert_eq!("4,6,3,5,6,3,5,2,1,0", computer.flush());
+  }
+
+  #[test]
+  fn parses_registers_and_program() {
+    let computer = parse_input("Register A: 729\nRegister B: 1\nRegister C: 2\n\nProgram: 0,1,5,4,3,0").unwrap();
+
+    assert_eq!(computer.register(Register::A), 729);
+    assert_eq!(computer.register(Register::C), 2);
+    assert_eq!(computer.program(), [0, 1, 5, 4, 3, 0]);
+    assert!(parse_input("Register A: 729\n\nProgram: 0,1").is_err());
+  }
+
+  #[test]
+  fn reports_where_the_input_is_invalid() {
+    let error = |input: &str| parse_input(input).unwrap_err().to_string();
+
+    assert_eq!(error("Register A: 729\nRegister B: x1"), "Invalid input at line 2, column 13: `x1`: invalid digit found in string");
+    assert_eq!(error("Register A: 729\n\nProgram: 0,1"), "Invalid input at line 3, column 1: Expected `Register B: <n>`, found `Program: 0,1`");
+    assert_eq!(error("Register A: 0\nRegister B: 0\nRegister C: 0\n"), "Invalid input at line 4, column 1: Expected `Program: <n>,<n>,...`");
+  }
+
+  #[test]
+  fn rejects_invalid_instructions() {
+    let error = |program| parse_input(&format!("Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: {program}")).unwrap_err().to_string();
+
+    assert_eq!(error("0,1,8,0"), "Invalid input at line 5, column 14: `8` at index 2 isn't a 3-bit number");
+    assert_eq!(error("0,1,5,7"), "Invalid input at line 5, column 16: Opcode 5 at index 2 uses the reserved combo operand 7");
+    assert_eq!(error("3,0,1,12"), "Invalid input at line 5, column 16: `12` at index 3 isn't a 3-bit number");
+  }
+
+  #[test]
+  fn run_until_copy_with_example_input() {
+    let mut computer = Computer::new();
+    computer.program = [0, 3, 5, 4, 3, 0].to_vec();
+
+    assert_eq!(computer.run_until_copy(), 117440);
+  }
+}
\ No newline at end of file
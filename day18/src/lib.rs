@@ -0,0 +1,141 @@
+/*
+ Advent of Code 2024 Day 18: RAM run
+
+ the input is a list of bytes that are falling.
+ the memory space is a two dimensional grid with coordinates that range from 0 to 70 both horizontally and vertically.
+ I start always at the 0,0 memory space and need to reach the exit at 70,70.
+ I cannot leave the the boundaries of the memory space and every time a byte fall into a memory space that coordinate gets corrupted.
+
+ Part one:
+
+ Simulate the first kilobyte (1024 bytes) falling onto your memory space.
+ Afterwards, what is the minimum number of fsteps neeeded to reach the exit?
+
+ Part two:
+
+ What are the coordinates of the first byte that will prevent the exit from being reachable from my starting position?
+
+ Solution:
+
+ Instead of dropping the bytes into the memory space, each location keeps the time it gets corrupted.
+ So the memory space after any number of bytes is the same grid, where the locations corrupted before that time are walls, and a BFS finds the steps to the exit.
+ Once the exit is cut off it never gets reachable again, so the first blocking byte is found with a binary search over the number of bytes fallen.
+*/
+use std::fmt::Display;
+
+use utils::pathfinding::bfs;
+use utils::{parse_field, Coordinate, Error, Grid, PuzzleInput, Result, Solution};
+
+pub struct RamRun;
+
+impl Solution for RamRun {
+  const DAY: u8 = 18;
+  const TITLE: &'static str = "RAM run";
+
+  type Input = MemorySpace;
+
+  fn parse(input: &PuzzleInput) -> Result<Self::Input> {
+    // The puzzle's memory space is 71x71 with a kilobyte fallen, examples set their own with the `size` and `bytes` params. i.e. 7x7 and 12.
+    parse_input(input, input.param_or("size", 71)?, input.param_or("bytes", 1024)?)
+  }
+
+  fn part_one(memory_space: &Self::Input) -> impl Display {
+    minimum_steps(memory_space, memory_space.fallen).map_or_else(|| "No path to the exit".to_string(), |steps| steps.to_string())
+  }
+
+  fn part_two(memory_space: &Self::Input) -> impl Display {
+    first_blocking_byte(memory_space).map_or_else(|| "The exit is never cut off".to_string(), |byte| format!("{},{}", byte.y, byte.x))
+  }
+}
+
+/// Where the bytes fall and when each location of the memory space gets corrupted,
+/// i.e. the number of bytes fallen before the first one falling there, or `usize::MAX` if none does.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MemorySpace {
+  pub bytes: Vec<Coordinate>,
+  pub corrupted_at: Grid<usize>,
+  /// Bytes fallen when looking for the minimum steps in part one.
+  pub fallen: usize,
+}
+
+/// Reads the `<x>,<y>` positions of the falling bytes, X being the distance from the left edge and Y from the top.
+pub fn parse_input(input: &str, size: usize, fallen: usize) -> Result<MemorySpace> {
+  let mut bytes = vec![];
+  let mut corrupted_at = Grid::filled(size, size, usize::MAX);
+
+  for (idx, line) in input.lines().enumerate() {
+    let (x, y) = line.split_once(',').ok_or_else(|| Error::parse(idx + 1, 1, "Expected `<x>,<y>`"))?;
+    let byte = Coordinate::new(parse_field(line, idx + 1, y)?, parse_field(line, idx + 1, x)?);
+
+    let time = corrupted_at
+      .get_mut(&byte)
+      .ok_or_else(|| Error::parse(idx + 1, 1, format!("The byte falls outside the {size}x{size} memory space")))?;
+    *time = (*time).min(bytes.len());
+    bytes.push(byte);
+  }
+
+  Ok(MemorySpace { bytes, corrupted_at, fallen })
+}
+
+/// Steps from the top left corner to the exit in the bottom right one, once the given number of bytes have fallen.
+pub fn minimum_steps(memory_space: &MemorySpace, fallen: usize) -> Option<usize> {
+  let grid = &memory_space.corrupted_at;
+  let is_safe = |location: &Coordinate| grid[location] >= fallen;
+  let exit = Coordinate::new(grid.height() as i32 - 1, grid.width() as i32 - 1);
+
+  let start = Coordinate::new(0, 0);
+  if !grid.contains(&start) || !is_safe(&start) {
+    return None;
+  }
+
+  bfs(start, |location| grid.neighbours(location).filter(is_safe).collect::<Vec<_>>(), |location| *location == exit).distance()
+}
+
+/// The first byte after which the exit can't be reached anymore, if any.
+pub fn first_blocking_byte(memory_space: &MemorySpace) -> Option<Coordinate> {
+  // the exit is reachable after `reachable` bytes and cut off after `blocked` ones, one more than all of them meaning never
+  let (mut reachable, mut blocked) = (0, memory_space.bytes.len() + 1);
+  minimum_steps(memory_space, reachable)?;
+
+  while blocked - reachable > 1 {
+    let fallen = (reachable + blocked) / 2;
+    if minimum_steps(memory_space, fallen).is_some() {
+      reachable = fallen;
+    } else {
+      blocked = fallen;
+    }
+  }
+
+  memory_space.bytes.get(blocked - 1).cloned()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use utils::TEST_CONFIG;
+
+  #[test]
+  fn example_exit_is_22_steps_away_after_12_bytes() {
+    let memory_space = TEST_CONFIG.load::<RamRun>().unwrap();
+
+    assert_eq!(minimum_steps(&memory_space, 12), Some(22));
+    assert_eq!(minimum_steps(&memory_space, 0), Some(12));
+  }
+
+  #[test]
+  fn example_exit_is_cut_off_by_byte_6_1() {
+    let memory_space = TEST_CONFIG.load::<RamRun>().unwrap();
+
+    assert_eq!(first_blocking_byte(&memory_space), Some(Coordinate::new(1, 6)));
+    assert_eq!(minimum_steps(&memory_space, 20), Some(24));
+    assert_eq!(minimum_steps(&memory_space, 21), None);
+  }
+
+  #[test]
+  fn rejects_bytes_outside_the_memory_space() {
+    let error = parse_input("1,2\n7,0", 7, 12).unwrap_err();
+
+    assert_eq!(error.to_string(), "Invalid input at line 2, column 1: The byte falls outside the 7x7 memory space");
+  }
+}
@@ -0,0 +1,28 @@
+/*
+ Compares trying the patterns recursively (`is_design_possible`) with a breadth first search over the design positions (`is_design_possible_bfs`).
+ The recursive version tries every way of making the designs that can't be made, so it doesn't finish on the puzzle input and only the search is measured there.
+*/
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use day19::{is_design_possible, is_design_possible_bfs, LinenLayout, Towels};
+use utils::{ChallengeConfig, TEST_CONFIG};
+
+fn compare_designs(c: &mut Criterion) {
+  let Towels { patterns, designs } = TEST_CONFIG.load::<LinenLayout>().unwrap();
+
+  let mut group = c.benchmark_group("example");
+  group.bench_function("recursive", |b| {
+    b.iter(|| designs.iter().filter(|design| is_design_possible(design.to_string(), &patterns)).count())
+  });
+  group.bench_function("bfs", |b| b.iter(|| designs.iter().filter(|design| is_design_possible_bfs(design, &patterns)).count()));
+  group.finish();
+
+  let Towels { patterns, designs } = ChallengeConfig::default().load::<LinenLayout>().unwrap();
+
+  let mut group = c.benchmark_group("puzzle");
+  group.bench_function("bfs", |b| b.iter(|| designs.iter().filter(|design| is_design_possible_bfs(design, &patterns)).count()));
+  group.finish();
+}
+
+criterion_group!(benches, compare_designs);
+criterion_main!(benches);
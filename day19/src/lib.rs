@@ -0,0 +1,176 @@
+/*
+ Advent of Code 2024 Day 19: Linen Layout
+
+ The puzzle input is a collection of available towel patterns and a list of desired designs.
+ I need to help an onsen hotel to arrange their towels.
+ Every towel is marked with a pattern of colored stripes. The stripes can be:
+  - w (white)
+  - r (red)
+  - g (green)
+  - u (blue)
+  - b (black) 
+ e.g. ggr would have a green stripe, a green strip and then a red stripe.
+ There are however standard designs that are combinations of colors.
+
+ Part one:
+
+ How many designs are possible?
+
+ Part two:
+*/
+use std::collections::VecDeque;
+use std::fmt::Display;
+
+use utils::{PuzzleInput, Result, Solution};
+
+pub struct LinenLayout;
+
+impl Solution for LinenLayout {
+  const DAY: u8 = 19;
+  const TITLE: &'static str = "Linen Layout";
+
+  type Input = Towels;
+
+  fn parse(input: &PuzzleInput) -> Result<Self::Input> {
+    Ok(parse_input(input))
+  }
+
+  fn part_one(towels: &Self::Input) -> impl Display {
+    possible_designs(towels.designs.clone(), &towels.patterns)
+  }
+
+  fn part_two(_: &Self::Input) -> impl Display {
+    "Not implemented yet"
+  }
+}
+
+/// The available towel patterns and the desired designs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Towels {
+  pub patterns: Vec<String>,
+  pub designs: Vec<String>,
+}
+
+pub fn parse_input(input: &str) -> Towels {
+  let mut patterns = vec![];
+  let mut designs = vec![];
+
+  for (idx, line) in input.lines().enumerate() {
+    if idx == 0 {
+      patterns = line
+        .split(", ")
+        .map(|line| line.to_string())
+        .collect();
+      
+      continue;
+    } else if idx == 1 {
+      continue;
+    }
+
+    designs.push(line.to_string());
+  }
+
+  Towels { patterns, designs }
+}
+
+/// Counts the designs that can be made with the patterns, searching each one breadth first as the recursive version doesn't finish on the puzzle input.
+pub fn possible_designs(ds: Vec<String>, ps: &[String]) -> i32 {
+  let mut possible_designs = 0;
+
+  for d in ds {
+    if is_design_possible_bfs(&d, ps) {
+      possible_designs += 1;
+    }
+  }
+
+  possible_designs
+}
+
+/// Breadth first version of `is_design_possible`, it goes through every design position reachable with the patterns.
+/// Each position is only visited once, so designs that can't be made don't blow up on all their prefixes.
+pub fn is_design_possible_bfs(d: &str, ps: &[String]) -> bool {
+  let mut visited = vec![false; d.len() + 1];
+  let mut queue = VecDeque::from([0]);
+
+  while let Some(position) = queue.pop_front() {
+    // if there is no more string to tokenize, the design is possible
+    if position == d.len() {
+      return true;
+    }
+
+    // queue the remaining string after each pattern that would start it
+    for pattern in ps.iter().filter(|p| d[position..].starts_with(p.as_str())) {
+      let next_position = position + pattern.len();
+      if !visited[next_position] {
+        visited[next_position] = true;
+        queue.push_back(next_position);
+      }
+    }
+  }
+
+  false
+}
+
+pub fn is_design_possible(d: String, ps: &[String]) -> bool {
+  let ps: Vec<String> = ps.iter().filter(|p| d.contains(*p)).cloned().collect();
+
+  // if there is no more string to tokenize, the design is possible
+  if d.is_empty() {
+    return true;
+  }
+
+  // if no pattern is possible, short circuit to false
+  if ps.is_empty() || !ps.iter().any(|ps| d.starts_with(ps)) {
+    return false;
+  }
+
+  let possible_patterns: Vec<String> = ps.iter().filter(|p| d.starts_with(*p)).cloned().collect();
+  for pattern in possible_patterns.iter() {
+    if let Some(next_pattern) = d.strip_prefix(pattern) {
+      if is_design_possible(next_pattern.to_string(), &ps) {
+        return true;
+      }
+    } 
+  }
+
+  false
+}
+
+#[cfg(test)]
+mod tests {
+  use utils::TEST_CONFIG;
+
+  use super::*;
+
+  #[test]
+  fn is_brwrr_possible_with_example_input() {
+    let design = "brwrr".to_string();
+    let patterns: Vec<String> = ["r", "wr", "b", "g", "bwu", "rb", "gb", "br"].iter().map(|p| p.to_string()).collect();
+    
+    assert!(is_design_possible(design, &patterns))
+  }
+
+  #[test]
+  fn is_ubwu_impossible_with_example_input() {
+    let design = "ubwu".to_string();
+    let patterns: Vec<String> = ["r", "wr", "b", "g", "bwu", "rb", "gb", "br"].iter().map(|p| p.to_string()).collect();
+   
+    assert!(!is_design_possible(design, &patterns))
+  }
+
+  #[test]
+  fn bfs_agrees_with_the_recursive_version_on_example_input() {
+    let Towels { patterns, designs } = TEST_CONFIG.load::<LinenLayout>().unwrap();
+
+    for design in designs {
+      assert_eq!(is_design_possible_bfs(&design, &patterns), is_design_possible(design.clone(), &patterns), "{design}");
+    }
+  }
+
+  #[test]
+  fn example_input_returns_6_possible_desings() { 
+    let Towels { patterns, designs } = TEST_CONFIG.load::<LinenLayout>().unwrap();
+
+    assert_eq!(possible_designs(designs, &patterns), 6);
+  }
+}
\ No newline at end of file
@@ -0,0 +1,104 @@
+/*
+ Advent of Code 2024 Day 2: Red-Nose Reports
+
+ Part one:
+
+ Our data are `reports`, one report per line. Each report is a list of numbers called `levels`, separated by spaces.
+ I need to figure out which report is `safe`.
+ A report is `safe` if both are true:
+   - all `levels` are increasing or decreasing
+   - Any two adjacent `levels` differ by at least one and at most three.
+
+ Part two:
+
+ There is a problem dampener that allows to have one single bad level.
+ If removing the bad level makes the report safe, then it is counted as safe.
+ With the same rules as before
+*/
+use std::fmt::Display;
+
+use utils::{PuzzleInput, Result, Solution};
+
+pub struct RedNoseReports;
+
+impl Solution for RedNoseReports {
+  const DAY: u8 = 2;
+  const TITLE: &'static str = "Red-Nose Reports";
+
+  type Input = Vec<Vec<u32>>;
+
+  fn parse(input: &PuzzleInput) -> Result<Self::Input> {
+    Ok(parse_input(input))
+  }
+
+  fn part_one(reports: &Self::Input) -> impl Display {
+    reports
+      .iter()
+      .filter(|report| inc_dec_rule(report.to_vec()) && differ_rule(report.to_vec()))
+      .count()
+  }
+
+  fn part_two(reports: &Self::Input) -> impl Display {
+    reports
+      .iter()
+      .filter(|report| (inc_dec_rule(report.to_vec()) && differ_rule(report.to_vec())) || problem_dampener(report.to_vec()))
+      .count()
+  }
+}
+
+pub fn parse_input(input: &str) -> Vec<Vec<u32>> {
+  let mut reports: Vec<Vec<u32>> = Vec::new();
+
+  for line in input.lines() {
+      let mut report: Vec<u32> = Vec::new();
+
+      for level in line.trim().split_ascii_whitespace() {
+          report.push(level.parse().expect("Couldn't parse level"))
+      }
+
+      // println!("Read a report: {:?}", report);
+
+      reports.push(report);
+  }
+
+  reports
+}
+
+pub fn inc_dec_rule(report: Vec<u32>) -> bool {
+  let increasing = report.is_sorted();
+  let decreasing = report
+    .iter()
+    .rev()
+    .is_sorted();
+
+  // println!("Report {:?} passes the inc_dec_rule?: {}", report, increasing || decreasing);
+  increasing || decreasing
+}
+
+pub fn differ_rule(report: Vec<u32>) -> bool {
+  for idx in 0..report.len() - 1 {
+      let diff = report[idx].abs_diff(report[idx + 1]);
+
+      if !(1..=3).contains(&diff) {
+          return false;
+      }
+  }
+
+  // println!("Report {:?} passes the differ rule?: {}", report, pass);
+  true
+}
+
+pub fn problem_dampener(report: Vec<u32>) -> bool {
+    let mut pass: bool = false;
+
+    for idx in 0..report.len() {
+        let mut report_copy = report.clone();
+        report_copy.remove(idx);
+
+        if inc_dec_rule(report_copy.clone()) && differ_rule(report_copy.clone()) {
+            pass = true;
+        }
+    }
+
+    pass
+}
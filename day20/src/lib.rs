@@ -0,0 +1,239 @@
+/*
+ Advent of Code 2024 Day 20: Race Condition
+
+ The programs compete to see who can finish in the fewest picoseconds.
+ The input is the map of a racetrack.
+ The track is marked with a . the walls with a #, the start with a S and the end with an E.
+ Each move in the racetrack takes 1 picosecond.
+ However, programs are allowed to cheat. Exactly once during a race a program may disable collision for up to 2 picoseconds.
+ This allows to pass through walls as if they were regular track.
+
+ Part one:
+
+ How many cheats would save you at least 100 picoseconds?
+
+ Part two:
+
+ Now collisions disabling can last up to 20 picoseconds.
+ How many cheats would save me at least 100 picoseconds?
+
+*/
+use std::fmt::Display;
+
+use utils::pathfinding::bfs;
+use utils::{find_marker, Coordinate, Direction, Grid, PuzzleInput, Result, Solution};
+
+pub struct RaceCondition;
+
+impl Solution for RaceCondition {
+  const DAY: u8 = 20;
+  const TITLE: &'static str = "Race Condition";
+
+  type Input = Racetrack;
+
+  fn parse(input: &PuzzleInput) -> Result<Self::Input> {
+    parse_input(input, input.param_or("threshold", 100)?)
+  }
+
+  fn part_one(racetrack: &Self::Input) -> impl Display {
+    find_cheats_atleast(racetrack, racetrack.threshold)
+  }
+
+  fn part_two(_: &Self::Input) -> impl Display {
+    "Not implemented yet"
+  }
+}
+
+/// The racetrack, where the race starts and ends and the least picoseconds a cheat has to save,
+/// which examples can change with the `threshold` param.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Racetrack {
+  pub map: Grid<char>,
+  pub start: Coordinate,
+  pub end: Coordinate,
+  pub threshold: i32,
+}
+
+pub fn parse_input(input: &str, threshold: i32) -> Result<Racetrack> {
+  let (map, markers) = Grid::parse(input.lines(), &['S', 'E'], |c| c)?;
+
+  Ok(Racetrack { map, start: find_marker(&markers, 'S')?, end: find_marker(&markers, 'E')?, threshold })
+}
+
+pub fn run_track(racetrack: &Racetrack) -> i32 {
+  track_path(racetrack).len() as i32 - 1
+}
+
+// returns the path done by the program
+pub fn track_path(racetrack: &Racetrack) -> Vec<Coordinate> {
+  let map = &racetrack.map;
+
+  bfs(racetrack.start.clone(), |pos| map.neighbours(pos).filter(|next| map[next] != '#').collect::<Vec<_>>(), |pos| *pos == racetrack.end)
+    .path()
+    .unwrap_or_default()
+}
+
+pub fn find_cheats(racetrack: &Racetrack, duration: i32) -> i32 {
+  let disable_duration = 2;
+  let race_track: Vec<Coordinate> = track_path(racetrack);
+
+  let mut cheats = 0;
+  for (curr_pos, curr) in race_track.clone().iter().enumerate() {
+    let possible_cheats = possible_cheats(curr.clone(), &racetrack.map);
+    for possible_cheat in possible_cheats {
+      let cheat_pos = race_track.iter().position(|pos| *pos == possible_cheat).unwrap();
+    
+      if cheat_pos < curr_pos {
+        continue;
+      }
+
+      let savings = (cheat_pos - curr_pos) as i32 - disable_duration;
+      
+      if savings == duration {
+        cheats += 1;
+      }
+    } 
+  } 
+
+  cheats
+}
+
+// A cheat can go through any wall as long as it lasts up to `secs`, so any later track position within that manhattan distance is reachable.
+pub fn find_cheats_upto(racetrack: &Racetrack, duration: i32, secs: i32) -> i32 {
+  let race_track: Vec<Coordinate> = track_path(racetrack);
+
+  let mut cheats = 0;
+  for (curr_pos, curr) in race_track.iter().enumerate() {
+    for (cheat_pos, cheat) in race_track.iter().enumerate().skip(curr_pos + 1) {
+      let disable_duration = (cheat.x - curr.x).abs() + (cheat.y - curr.y).abs();
+      if disable_duration > secs {
+        continue;
+      }
+
+      let savings = (cheat_pos - curr_pos) as i32 - disable_duration;
+
+      if savings == duration {
+        cheats += 1;
+      }
+    }
+  }
+
+  cheats
+}
+
+pub fn find_cheats_atleast(racetrack: &Racetrack, duration: i32) -> i32 {
+  let disable_duration = 2;
+  let race_track: Vec<Coordinate> = track_path(racetrack);
+
+  let mut cheats = 0;
+  for (curr_pos, curr) in race_track.clone().iter().enumerate() {
+    let possible_cheats = possible_cheats(curr.clone(), &racetrack.map);
+    for possible_cheat in possible_cheats {
+      let cheat_pos = race_track.iter().position(|pos| *pos == possible_cheat).unwrap();
+    
+      if cheat_pos < curr_pos {
+        continue;
+      }
+
+      let savings = (cheat_pos - curr_pos) as i32 - disable_duration;
+      
+      if savings >= duration {
+        cheats += 1;
+      }
+    } 
+  } 
+
+  cheats
+}
+
+fn possible_cheats(pos: Coordinate, map: &Grid<char>) -> Vec<Coordinate> {
+  let mut possible_cheats = Vec::new();
+
+  for direction in Direction::iter() {
+    let mut next_pos = pos.add_delta(&direction);
+    if let Some(value) = map.get(&next_pos) {
+      if *value == '#' {
+        next_pos = next_pos.add_delta(&direction);
+        if let Some(value) = map.get(&next_pos) {
+          if ['.', 'E'].contains(value) {
+            possible_cheats.push(next_pos);
+          }
+        }
+      }
+    }
+  }
+
+  possible_cheats
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use utils::TEST_CONFIG;
+
+  #[test]
+  fn start_position_is_right() {
+    let racetrack = TEST_CONFIG.load::<RaceCondition>().unwrap();
+    
+    assert_eq!(racetrack.start, Coordinate::new(3, 1));
+  }
+
+  #[test]
+  fn finish_position_is_right() {
+    let racetrack = TEST_CONFIG.load::<RaceCondition>().unwrap();
+    
+    assert_eq!(racetrack.end, Coordinate::new(7, 5));
+  }
+
+  #[test] 
+  fn example_racetrack_finishes_in_84_picoseconds() {
+    let racetrack = TEST_CONFIG.load::<RaceCondition>().unwrap();
+
+    assert_eq!(run_track(&racetrack), 84);
+  }
+
+  #[test]
+  fn test_example_2_picoseconds_cheats() {
+    let racetrack = TEST_CONFIG.load::<RaceCondition>().unwrap();
+    
+    assert_eq!(find_cheats(&racetrack, 2), 14, "There should be 14 cheats that save 2 picoseconds");
+    assert_eq!(find_cheats(&racetrack, 4), 14, "There should be 14 cheats that save 4 picoseconds");
+    assert_eq!(find_cheats(&racetrack, 6),  2, "There should be 2 cheats that save 6 picoseconds");
+    assert_eq!(find_cheats(&racetrack, 8),  4, "There should be 4 cheats that save 8 picoseconds");
+    assert_eq!(find_cheats(&racetrack, 10), 2, "There should be 2 cheats that save 10 picoseconds");
+    assert_eq!(find_cheats(&racetrack, 12), 3, "There should be 3 cheats that save 12 picoseconds");
+    assert_eq!(find_cheats(&racetrack, 20), 1, "There should be 1 cheats that save 20 picoseconds");
+    assert_eq!(find_cheats(&racetrack, 36), 1, "There should be 1 cheats that save 36 picoseconds");
+    assert_eq!(find_cheats(&racetrack, 38), 1, "There should be 1 cheats that save 38 picoseconds");
+    assert_eq!(find_cheats(&racetrack, 40), 1, "There should be 1 cheats that save 40 picoseconds");
+    assert_eq!(find_cheats(&racetrack, 64), 1, "There should be 1 cheats that save 64 picoseconds");
+  }
+
+  #[test]
+  fn test_example_atleast_cheats() {
+    let racetrack = TEST_CONFIG.load::<RaceCondition>().unwrap();
+    
+    assert_eq!(find_cheats_atleast(&racetrack, 20), 5, "There should be at least 5 cheats that save 20 picoseconds");
+  }
+
+  #[test]
+  fn test_example_20_picoseconds_cheats() {
+    let racetrack = TEST_CONFIG.load::<RaceCondition>().unwrap();
+    
+    assert_eq!(find_cheats_upto(&racetrack, 50, 20), 32, "There should be 32 cheats that save 50 picoseconds");
+    assert_eq!(find_cheats_upto(&racetrack, 52, 20), 31, "There should be 31 cheats that save 52 picoseconds");
+    assert_eq!(find_cheats_upto(&racetrack, 54, 20), 29, "There should be 29 cheats that save 54 picoseconds");
+    assert_eq!(find_cheats_upto(&racetrack, 56, 20), 39, "There should be 39 cheats that save 56 picoseconds");
+    assert_eq!(find_cheats_upto(&racetrack, 58, 20), 25, "There should be 25 cheats that save 58 picoseconds");
+    // assert_eq!(find_cheats(&racetrack, 60), 23, "There should be 23 cheats that save 60 picoseconds");
+    // assert_eq!(find_cheats(&racetrack, 62), 20, "There should be 20 cheats that save 62 picoseconds");
+    // assert_eq!(find_cheats(&racetrack, 64), 19, "There should be 19 cheats that save 64 picoseconds");
+    // assert_eq!(find_cheats(&racetrack, 66), 12, "There should be 12 cheats that save 66 picoseconds");
+    // assert_eq!(find_cheats(&racetrack, 68), 14, "There should be 14 cheats that save 68 picoseconds");
+    // assert_eq!(find_cheats(&racetrack, 70), 12, "There should be 12 cheats that save 70 picoseconds");
+    // assert_eq!(find_cheats(&racetrack, 72), 22, "There should be 22 cheats that save 72 picoseconds");
+    // assert_eq!(find_cheats(&racetrack, 74),  4, "There should be 3 cheats that save 74 picoseconds");
+    // assert_eq!(find_cheats(&racetrack, 76),  3, "There should be 4 cheats that save 76 picoseconds");
+  }
+}
\ No newline at end of file
@@ -0,0 +1,212 @@
+/*
+ Advent of Code 2024 Day 21: Keypad Conundrum
+
+ The door of the ship has a numeric keypad, but it can only be reached by a robot, which is controlled with a directional keypad.
+ That directional keypad is also pressed by a robot, controlled with another directional keypad, and so on until the one I press.
+ The robots' arms start pointing at the A button of their keypad and can never point at the gap of it.
+ The input are the codes to type in the door, the complexity of a code is the number of buttons I press to type it times its numeric part.
+
+ Part one:
+
+ What is the sum of the complexities of the codes with two robots using directional keypads?
+
+ Part two:
+
+ And with twenty-five robots using directional keypads?
+
+ Solution:
+
+ Every button pressed on a keypad ends with the arm of the keypad after it back on A, so moving from a button to another and pressing it
+ costs the same no matter what was typed before. That cost is the cheapest of moving horizontally then vertically or the other way around,
+ never zig zagging as repeating the same direction is just pressing A again on the next keypad, and avoiding the gap.
+ Each of those moves is a sequence typed on the next keypad, so the costs are worked out recursively and memoized by buttons and depth.
+*/
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use utils::{Coordinate, Direction, Error, PuzzleInput, Result, Solution};
+
+pub struct KeypadConundrum;
+
+impl Solution for KeypadConundrum {
+  const DAY: u8 = 21;
+  const TITLE: &'static str = "Keypad Conundrum";
+
+  type Input = Vec<String>;
+
+  fn parse(input: &PuzzleInput) -> Result<Self::Input> {
+    parse_input(input)
+  }
+
+  fn part_one(codes: &Self::Input) -> impl Display {
+    complexities(codes, 2)
+  }
+
+  fn part_two(codes: &Self::Input) -> impl Display {
+    complexities(codes, 25)
+  }
+}
+
+/// The door's keypad, row after row with a space for the gap.
+pub const NUMERIC_KEYPAD: &[&str] = &["789", "456", "123", " 0A"];
+/// The robots' keypad, row after row with a space for the gap.
+pub const DIRECTIONAL_KEYPAD: &[&str] = &[" ^A", "<v>"];
+
+pub fn parse_input(input: &str) -> Result<Vec<String>> {
+  let door = Keypad::new(NUMERIC_KEYPAD);
+
+  for (idx, code) in input.lines().enumerate() {
+    if let Some((column, button)) = code.chars().enumerate().find(|(_, button)| !door.buttons.contains_key(button)) {
+      return Err(Error::parse(idx + 1, column + 1, format!("The door's keypad has no `{button}` button")));
+    }
+  }
+
+  Ok(input.lines().map(String::from).collect())
+}
+
+/// Where the buttons of a keypad are and the gap robots can't point at.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keypad {
+  pub buttons: HashMap<char, Coordinate>,
+  pub gap: Option<Coordinate>,
+}
+
+impl Keypad {
+  /// Builds a keypad out of its rows of buttons, where a space is the gap.
+  pub fn new(rows: &[&str]) -> Self {
+    let mut buttons = HashMap::new();
+    let mut gap = None;
+
+    for (x, row) in rows.iter().enumerate() {
+      for (y, button) in row.chars().enumerate() {
+        let coordinate = Coordinate::new(x as i32, y as i32);
+        match button {
+          ' ' => gap = Some(coordinate),
+          _ => { buttons.insert(button, coordinate); },
+        }
+      }
+    }
+
+    Self { buttons, gap }
+  }
+
+  /// The directional buttons to press to move the arm from a button to another and press it, without pointing at the gap.
+  fn moves(&self, from: char, to: char) -> Vec<String> {
+    let (from, to) = (&self.buttons[&from], &self.buttons[&to]);
+
+    let vertical_direction = if to.x < from.x { Direction::Up } else { Direction::Down };
+    let horizontal_direction = if to.y < from.y { Direction::Left } else { Direction::Right };
+    let vertical = vertical_direction.to_char().to_string().repeat(from.x.abs_diff(to.x) as usize);
+    let horizontal = horizontal_direction.to_char().to_string().repeat(from.y.abs_diff(to.y) as usize);
+
+    let mut moves = vec![];
+    if self.gap != Some(Coordinate::new(from.x, to.y)) {
+      moves.push(format!("{horizontal}{vertical}A"));
+    }
+    if self.gap != Some(Coordinate::new(to.x, from.y)) {
+      moves.push(format!("{vertical}{horizontal}A"));
+    }
+    moves.dedup();
+
+    moves
+  }
+}
+
+/// The keypads between the door and me, the door's one first. I press the last one's buttons.
+#[derive(Debug, Clone)]
+pub struct KeypadChain {
+  keypads: Vec<Keypad>,
+  costs: HashMap<(char, char, usize), usize>,
+}
+
+impl KeypadChain {
+  /// A chain with the door's keypad and as many robots using a directional keypad after it.
+  pub fn new(door: Keypad, controls: Keypad, robots: usize) -> Self {
+    let mut keypads = vec![door];
+    keypads.extend(std::iter::repeat_n(controls, robots));
+
+    Self { keypads, costs: HashMap::new() }
+  }
+
+  /// Buttons I have to press to type the code in the door.
+  pub fn presses(&mut self, code: &str) -> usize {
+    self.sequence_cost(code, 0)
+  }
+
+  fn sequence_cost(&mut self, sequence: &str, depth: usize) -> usize {
+    let mut arm = 'A';
+    let mut cost = 0;
+    for button in sequence.chars() {
+      cost += self.press_cost(arm, button, depth);
+      arm = button;
+    }
+
+    cost
+  }
+
+  // Buttons I have to press to move the arm of the keypad at the given depth from a button to another and press it.
+  fn press_cost(&mut self, from: char, to: char, depth: usize) -> usize {
+    if depth == self.keypads.len() {
+      return 1;
+    }
+    if let Some(cost) = self.costs.get(&(from, to, depth)) {
+      return *cost;
+    }
+
+    let cost = self.keypads[depth]
+      .moves(from, to)
+      .iter()
+      .map(|moves| self.sequence_cost(moves, depth + 1))
+      .min()
+      .unwrap();
+    self.costs.insert((from, to, depth), cost);
+
+    cost
+  }
+}
+
+/// Sum of the codes' complexities with the given number of robots using directional keypads.
+pub fn complexities(codes: &[String], robots: usize) -> usize {
+  let mut chain = KeypadChain::new(Keypad::new(NUMERIC_KEYPAD), Keypad::new(DIRECTIONAL_KEYPAD), robots);
+
+  codes
+    .iter()
+    .map(|code| chain.presses(code) * numeric_part(code))
+    .sum()
+}
+
+fn numeric_part(code: &str) -> usize {
+  code.chars().filter(char::is_ascii_digit).collect::<String>().parse().unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use utils::TEST_CONFIG;
+
+  #[test]
+  fn example_codes_presses() {
+    let mut chain = KeypadChain::new(Keypad::new(NUMERIC_KEYPAD), Keypad::new(DIRECTIONAL_KEYPAD), 2);
+
+    assert_eq!(chain.presses("029A"), 68);
+    assert_eq!(chain.presses("980A"), 60);
+    assert_eq!(chain.presses("179A"), 68);
+    assert_eq!(chain.presses("456A"), 64);
+    assert_eq!(chain.presses("379A"), 64);
+  }
+
+  #[test]
+  fn example_complexities_sum_126384() {
+    let codes = TEST_CONFIG.load::<KeypadConundrum>().unwrap();
+
+    assert_eq!(complexities(&codes, 2), 126384);
+  }
+
+  #[test]
+  fn rejects_buttons_not_in_the_door() {
+    let error = parse_input("029A\n9B8A").unwrap_err();
+
+    assert_eq!(error.to_string(), "Invalid input at line 2, column 2: The door's keypad has no `B` button");
+  }
+}
@@ -1,17 +1,5 @@
-/*
- Advent of Code 2024 Day 10
-
- Part one:
-
- Part two:
-*/
-use utils::{ChallengeConfig, ChallengePart};
+use day21::KeypadConundrum;
 
 fn main() {
-    let challenge_config = ChallengeConfig::get();
-    
-    match challenge_config.part {
-      ChallengePart::One => println!("Not implemented yet"),
-      ChallengePart::Two => println!("Not implemented yet"),
-    }
-}
\ No newline at end of file
+  utils::run::<KeypadConundrum>();
+}
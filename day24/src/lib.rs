@@ -0,0 +1,307 @@
+
+/*
+ Advent of Code 2024 Day 24: Crossed Wires
+
+ The puzzle input is info about logical gate connections and initial wire values.
+ There are no loops. Gates wait until both inputs are received before producing a value.
+ Wires can carry 0,1 or no value at all. Outputs do not change until the whole system is reset.
+ Each wire is connected to at most one gate output, but can be connected to many gate inputs.
+ There are only three types of gates: AND, OR and XOR.
+
+ Part one:
+
+ What decimal number does the system of gatest and wires output on the wires starting with z?
+
+ Part two:
+*/
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+
+use utils::{column_of, Error, PuzzleInput, Result, Solution};
+
+/// Value carried by a wire, none until its gate has produced it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WireState {
+  One,
+  Zero,
+  None,
+}
+
+impl fmt::Display for WireState {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      WireState::None => write!(f, "None"),
+      WireState::One => write!(f, "1"),
+      WireState::Zero => write!(f, "0"),
+    }
+  }
+}
+
+impl WireState {  /// Returns the numerical value of the wire or None. Useful for arithmetic operations
+  fn to_val(&self) -> i32 {
+    match self {
+      WireState::One => 1,
+      WireState::Zero => 0,
+      WireState::None => panic!("Cannot operate on a not ready wire state"),
+    }
+  }
+
+  /// Transforms a numerical value to the Wire enum.
+  fn from_int(v: i32) -> WireState {
+    if v > 0 { WireState::One } else { WireState::Zero }
+  }
+
+  fn from_str(v: &str) -> Option<WireState> {
+    match v {
+      "0" => Some(WireState::Zero),
+      "1" => Some(WireState::One),
+      _ => None
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+pub enum LogicalOp {
+  And,
+  Or,
+  Xor,
+}
+
+impl LogicalOp {
+  fn from(v: &str) -> Option<LogicalOp> {
+    match v {
+      "AND" => Some(LogicalOp::And), 
+      "OR" => Some(LogicalOp::Or),
+      "XOR" => Some(LogicalOp::Xor),
+      _ => None
+    }
+  }
+
+  fn calculate(&self, a: i32, b: i32) -> i32 {
+    match self {
+      LogicalOp::And => a & b,
+      LogicalOp::Or => a | b,
+      LogicalOp::Xor => a ^ b,
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct Gate {
+  pub inputs: (String, String),
+  pub output: String,
+  pub operation: LogicalOp
+}
+
+impl Gate {
+  fn can_operate(&self, wire_states: &HashMap<String, WireState>) -> bool {
+    let is_ready = |wire| wire_states.get(wire).is_some_and(|state| *state != WireState::None);
+
+    is_ready(&self.inputs.0) && is_ready(&self.inputs.1)
+  }
+
+  fn has_operated(&self, wire_states: &HashMap<String, WireState>) -> bool {
+    wire_states.get(&self.output).is_some_and(|state| *state != WireState::None)
+  }
+
+  fn process_output(&mut self, wire_states: &mut HashMap<String, WireState>) {
+    if !self.can_operate(wire_states) {
+      panic!("One of the inputs is not ready to operate for gate with inputs: {} {}", self.inputs.0, self.inputs.1)
+    }
+
+    let result = self.operation.calculate(
+      wire_states.get(&self.inputs.0).unwrap().to_val(),
+      wire_states.get(&self.inputs.1).unwrap().to_val(), 
+    );
+
+    wire_states.insert(self.output.clone(), WireState::from_int(result));
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct SystemConfig {
+  wires: HashMap<String, WireState>,
+  gates: Vec<Gate>,
+}
+
+impl SystemConfig {
+  /// Builds the system out of the initial wire values and its gates, the gates outputs having no value yet.
+  pub fn new(mut wires: HashMap<String, WireState>, gates: Vec<Gate>) -> Self {
+    for gate in &gates {
+      wires.entry(gate.output.clone()).or_insert(WireState::None);
+    }
+
+    Self { wires, gates }
+  }
+
+  pub fn gates(&self) -> &[Gate] {
+    &self.gates
+  }
+
+  /// Returns the value of a wire, if the system has it.
+  pub fn wire(&self, label: &str) -> Option<&WireState> {
+    self.wires.get(label)
+  }
+
+  /// Sets the value of a wire, e.g. to try other x and y inputs.
+  pub fn set_wire(&mut self, label: &str, state: WireState) {
+    self.wires.insert(label.to_string(), state);
+  }
+
+  /// Operates the gates until every z wire has a value.
+  /// Returns the z wires left without one when the gates stop producing values, e.g. because of a loop, sorted.
+  pub fn run(&mut self) -> Vec<String> {
+    loop {
+      let mut waiting: Vec<String> = self
+        .wires
+        .iter()
+        .filter(|(label, state)| label.starts_with("z") && **state == WireState::None)
+        .map(|(label, _)| label.clone())
+        .collect();
+      if waiting.is_empty() {
+        return waiting;
+      }
+
+      let mut progressed = false;
+      for gate in &mut self.gates {
+        if gate.can_operate(&self.wires) && !gate.has_operated(&self.wires) {
+          gate.process_output(&mut self.wires);
+          progressed = true;
+        }
+      }
+
+      if !progressed {
+        waiting.sort();
+        return waiting;
+      }
+    }
+  }
+
+  /// Returns the decimal number formed by the z wires, z00 being the least significant bit.
+  pub fn output(&self) -> u64 {
+    let mut outputs: Vec<(String, String)> = self.wires.iter().filter_map(|(label, state)| {
+      if label.starts_with("z") {
+        return Some((label.clone(), state.to_string()));
+      }
+      None
+    }).collect();
+
+    outputs.sort();
+    outputs.reverse();
+    
+    // println!("raw collection: {outputs:?}");
+
+    let outputs: Vec<String> = outputs.iter().map(|(_, value)| value.clone()).collect();
+    // println!("binary: {}", outputs.concat());
+    u64::from_str_radix(&outputs.concat(), 2).unwrap()
+  }
+}
+
+pub fn parse_input(input: &PuzzleInput) -> Result<SystemConfig> {
+  let wires = input.parse_section("inputs", parse_wires)?;
+  let gates = input.parse_section("gates", |gates| parse_gates(gates, &wires))?;
+
+  Ok(SystemConfig::new(wires, gates))
+}
+
+// get inputs starting configuration
+fn parse_wires(inputs: &str) -> Result<HashMap<String, WireState>> {
+  let mut wires = HashMap::new();
+
+  for (idx, inputs_line) in inputs.lines().enumerate() {
+    let (wire_label, wire_state) = inputs_line
+      .split_once(": ")
+      .ok_or_else(|| Error::parse(idx + 1, 1, "Expected `<wire>: <0|1>`"))?;
+
+    let wire_state = WireState::from_str(wire_state)
+      .ok_or_else(|| Error::parse(idx + 1, column_of(inputs_line, wire_state), format!("Invalid wire state `{wire_state}`")))?;
+
+    wires.insert(wire_label.to_string(), wire_state);
+  }
+
+  Ok(wires)
+}
+
+// get gates configuration, each of their inputs has to be an initial wire or the output of a gate
+fn parse_gates(gates: &str, wires: &HashMap<String, WireState>) -> Result<Vec<Gate>> {
+  let mut parsed_gates = Vec::new();
+  let mut gate_inputs = Vec::new();
+
+  for (idx, gates_line) in gates.lines().enumerate() {
+    let parsed_line: Vec<&str> = gates_line.split(" ").collect();
+
+    let [input_a, operation, input_b, "->", output] = parsed_line[..] else {
+      return Err(Error::parse(idx + 1, 1, "Expected `<wire> <AND|OR|XOR> <wire> -> <wire>`"));
+    };
+
+    let operation = LogicalOp::from(operation)
+      .ok_or_else(|| Error::parse(idx + 1, column_of(gates_line, operation), format!("Unknown logical operation `{operation}`")))?;
+
+    gate_inputs.extend([(idx, gates_line, input_a), (idx, gates_line, input_b)]);
+    parsed_gates.push(Gate {
+      inputs: (input_a.to_string(), input_b.to_string()),
+      output: output.to_string(),
+      operation,
+    });
+  } 
+
+  for (idx, gates_line, input) in gate_inputs {
+    if !wires.contains_key(input) && !parsed_gates.iter().any(|gate| gate.output == input) {
+      return Err(Error::parse(idx + 1, column_of(gates_line, input), format!("Unknown wire `{input}`, it's neither an input nor a gate output")));
+    }
+  }
+
+  Ok(parsed_gates)
+}
+
+pub struct CrossedWires;
+
+impl Solution for CrossedWires {
+  const DAY: u8 = 24;
+  const TITLE: &'static str = "Crossed Wires";
+  const SECTIONS: &'static [&'static str] = &["inputs", "gates"];
+
+  type Input = SystemConfig;
+
+  fn parse(input: &PuzzleInput) -> Result<Self::Input> {
+    parse_input(input)
+  }
+
+  fn part_one(system_config: &Self::Input) -> impl Display {
+    let mut system_config = system_config.clone();
+    let waiting = system_config.run();
+    if !waiting.is_empty() {
+      return format!("The gates never produce {}", waiting.join(", "));
+    }
+
+    system_config.output().to_string()
+  }
+
+  fn part_two(_: &Self::Input) -> impl Display {
+    "Not implemented yet"
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn parse(inputs: &str, gates: &str) -> Result<SystemConfig> {
+    parse_input(&PuzzleInput::split(format!("{inputs}\n\n{gates}"), CrossedWires::SECTIONS))
+  }
+
+  #[test]
+  fn rejects_gates_with_unknown_inputs() {
+    let error = parse("x00: 1", "x00 AND q00 -> z00").unwrap_err();
+
+    assert_eq!(error.to_string(), "In section `gates`: Invalid input at line 1, column 9: Unknown wire `q00`, it's neither an input nor a gate output");
+  }
+
+  #[test]
+  fn stops_when_the_gates_loop() {
+    let mut system = parse("x00: 1\ny00: 0", "x00 OR y00 -> z00\nx00 AND a00 -> z01\nz01 XOR y00 -> a00").unwrap();
+
+    assert_eq!(system.run(), ["z01"]);
+    assert_eq!(CrossedWires::part_one(&system).to_string(), "The gates never produce z01");
+  }
+}
@@ -0,0 +1,111 @@
+/*
+ Advent of Code 2024 Day 25: Code Chronicle
+
+ The puzzle input are schematics of every lock and every key for a floor I'm on.
+ The locks are schematics with the top row filled with # and the bottom row empty, i.e. filled with '.'.
+ The keys are the contrary to the locks.
+
+ Part one:
+
+ How many unique lock/key pairs fit together without overlapping in any column?
+
+ Part two:
+*/
+use std::fmt::Display;
+
+use utils::{PuzzleInput, Result, Solution};
+
+pub struct CodeChronicle;
+
+impl Solution for CodeChronicle {
+  const DAY: u8 = 25;
+  const TITLE: &'static str = "Code Chronicle";
+
+  type Input = Schematics;
+
+  fn parse(input: &PuzzleInput) -> Result<Self::Input> {
+    Ok(parse_input(input))
+  }
+
+  fn part_one(schematics: &Self::Input) -> impl Display {
+    get_unique_pairs(&schematics.keys, &schematics.locks)
+  }
+
+  fn part_two(_: &Self::Input) -> impl Display {
+    "Not implemented yet"
+  }
+}
+
+pub type Combination = [i32; 5];
+
+/// Heights of the pins of every key and lock schematic.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Schematics {
+  pub keys: Vec<Combination>,
+  pub locks: Vec<Combination>,
+}
+
+pub fn parse_input(input: &str) -> Schematics {
+  let mut keys = vec![];
+  let mut locks = vec![];
+
+
+  let lines: Vec<&str> = input.lines().collect();
+
+  let mut i = 0;
+  while i < lines.len() {
+    if lines[i].is_empty() {
+      i += 1;
+      continue;
+    }
+
+    let slice = &lines[i..i+7];
+    // println!("slice {slice:?}");
+    let mut next_combination = [0, 0, 0, 0, 0];
+
+    let inner_slice = &slice[1..slice.len()-1]; // remove the ends
+
+    for line in inner_slice.iter() {
+      for (idx, c) in line.chars().enumerate() {
+        next_combination[idx] += if c == '.' { 0 } else { 1 };
+      }
+    }
+
+    // println!("next combination: {next_combination:?}");
+    // is key or lock
+    if slice[0].chars().all(|c| c == '#') {
+      keys.push(next_combination);
+    } else if slice[0].chars().all(|c| c == '.' ) {
+      locks.push(next_combination);
+    } else {
+      unreachable!("This shouldn't happen")
+    }
+
+    i += 7 
+  }
+  
+  Schematics { keys, locks }
+}
+
+pub fn get_unique_pairs(keys: &[Combination], locks: &[Combination]) -> i32 {
+  let mut unique_pairs = 0;
+
+  for key in keys {
+    for lock in locks {
+      let mut overlaps = false;
+
+      for (key_height, lock_height) in key.iter().zip(lock.iter()) {
+        if key_height + lock_height > 5 {
+          overlaps = true;
+        }
+      }
+
+      if !overlaps {
+        unique_pairs += 1;
+      }
+    }
+
+  }
+
+  unique_pairs
+}
\ No newline at end of file
@@ -0,0 +1,87 @@
+/*
+ Advent of Code 2024 Day 3: Mull it over
+
+ Part one:
+
+ The memory (puzzle input) is `corrupted`
+ the goal is to multiply some numbers but there are some invalid characters that should be `ignored`
+ find in the text all mul(x,y) which are not corrupted, multiply their numbers and add them all together.
+ 
+ Part two:
+
+ Similar to the first but now there are do() and don't() instructions that enable or disables future mul instructions.
+ they all start `enabled`
+*/
+use regex::Regex;
+
+use std::fmt::Display;
+
+use utils::{PuzzleInput, Result, Solution};
+
+pub struct MullItOver;
+
+impl Solution for MullItOver {
+  const DAY: u8 = 3;
+  const TITLE: &'static str = "Mull it over";
+
+  type Input = String;
+
+  fn parse(input: &PuzzleInput) -> Result<Self::Input> {
+    Ok(get_memory_line(input))
+  }
+
+  fn part_one(memory_line: &Self::Input) -> impl Display {
+    part_one(memory_line.clone())
+  }
+
+  fn part_two(memory_line: &Self::Input) -> impl Display {
+    part_two(memory_line.clone())
+  }
+}
+
+pub fn get_memory_line(input: &str) -> String {
+  input.lines().next().unwrap_or_default().to_string()
+}
+
+pub fn part_one(line: String) -> u32 {
+  let mut result = 0;
+  let re = Regex::new(r"mul\((\d+),(\d*)\)").unwrap();
+
+  for cap in re.captures_iter(line.as_str()) {
+    let x: u32 =  cap.get(1).unwrap().as_str().parse().unwrap(); // First number (x)
+    let y: u32 = cap.get(2).unwrap().as_str().parse().unwrap(); // Second number (y)
+
+    result += x * y;
+
+    // println!("Found: mul({}, {}) Res: {}", x, y, x * y);
+  }
+
+  result
+}
+
+pub fn part_two(line: String) -> u32 {
+  let mut result = 0;
+  let re = Regex::new(r"do\(\)|don't\(\)|mul\((\d+),(\d*)\)").unwrap();
+
+  let mut enable_mul = true;
+  for cap in re.captures_iter(line.as_str()) {
+    let captured_element = cap.get(0).unwrap().as_str();
+    
+    if captured_element == "don't()" {
+      enable_mul  = false;
+      continue;
+    } else if captured_element == "do()" {
+      enable_mul = true;
+      continue;
+    }
+
+    if enable_mul {
+      let x: u32 =  cap.get(1).unwrap().as_str().parse().unwrap(); // First number (x)
+      let y: u32 = cap.get(2).unwrap().as_str().parse().unwrap(); // Second number (y)
+    
+      result += x * y;
+    }
+  }
+
+  result
+}
\ No newline at end of file
@@ -0,0 +1,163 @@
+/*
+ Advent of Code 2024 Day 5: Print Queue
+
+ The puzzle is a set of rules for constructing sleigh launch safety manual .
+ The new pages for the safety manuals must be printed in very specific order.
+ The notation X | Y means that X must be printed at some point before page Y
+ Every line of pages is called an update. That update must be in the order specified by the rules.
+
+ Part one:
+
+ Determine the updates that are in the correct order.
+ What do I get if I add up all the middle page numbers of those correctly-ordered updates?
+
+ Part two:
+
+ Determine the updates that are incorrectly ordered.
+ Use the ordering rules to rearrange them.
+ Add up the middle page numberes similar to previous part.
+
+ Solution: 
+
+ At the beginning I went with a hashmap and a simple hashmap checking for rules.
+ I then found this answer https://www.youtube.com/watch?v=TymMkbH8e6A to solver part two which changed completely my point ofview of the problem.
+ If any number is not in the after set, i.e. that number should be after another one, it can be placed in one index.
+ After applying that algorithm it worked excellently and refactored the whole solutions. 
+*/
+use std::collections::{HashMap,HashSet};
+
+use std::fmt::Display;
+
+use utils::{PuzzleInput, Result, Solution};
+
+pub struct PrintQueue;
+
+impl Solution for PrintQueue {
+  const DAY: u8 = 5;
+  const TITLE: &'static str = "Print Queue";
+  const SECTIONS: &'static [&'static str] = &["page_ordering_rules", "pages"];
+
+  type Input = SafetyManual;
+
+  fn parse(input: &PuzzleInput) -> Result<Self::Input> {
+    Ok(parse_input(input.section("page_ordering_rules")?, input.section("pages")?))
+  }
+
+  fn part_one(manual: &Self::Input) -> impl Display {
+    let page_updates: Vec<Vec<u32>> = manual
+      .updates
+      .iter()
+      .filter(|page_update| check_rules(&manual.rules, page_update))
+      .cloned()
+      .collect();
+
+    middle_page_sum(&page_updates)
+  }
+
+  fn part_two(manual: &Self::Input) -> impl Display {
+    let page_updates: Vec<Vec<u32>> = manual
+      .updates
+      .iter()
+      .filter(|page_update| !check_rules(&manual.rules, page_update))
+      .map(|page_update| correct_incorrect_update(page_update, &manual.rules))
+      .collect();
+
+    middle_page_sum(&page_updates)
+  }
+}
+
+pub fn middle_page_sum(page_updates: &[Vec<u32>]) -> u32 {
+  page_updates
+  .iter()
+  .fold(0, |accum, update| accum + update[update.len() / 2])
+}
+
+pub fn check_rules(page_ordering_rules: &HashMap<u32, OrderingSets>, page_update: &[u32]) -> bool {
+  for (page_idx, page) in page_update.iter().enumerate() {
+    let ordering_sets = page_ordering_rules.get(page).unwrap();
+
+    for other_page in &page_update[page_idx + 1..] {
+      if ordering_sets.before.contains(other_page) {
+        return false
+      }
+    }
+  }
+
+  true
+}
+
+/// Pages that have to be printed before and after a page.
+#[derive(Debug)]
+pub struct OrderingSets {
+  pub before: HashSet<u32>,
+  pub after: HashSet<u32>
+} 
+
+/// The page ordering rules of every page and the pages of each update.
+#[derive(Debug)]
+pub struct SafetyManual {
+  pub rules: HashMap<u32, OrderingSets>,
+  pub updates: Vec<Vec<u32>>,
+}
+
+pub fn parse_input(rules: &str, updates: &str) -> SafetyManual {
+  let mut page_rules = HashMap::new();
+  let mut page_updates_list: Vec<Vec<u32>> = Vec::new();
+
+  for line in updates.lines() {
+    let pages_line: Vec<u32> = line.split(",").map(|s| s.parse().unwrap()).collect();
+    page_updates_list.push(pages_line)
+  }
+
+  for line in rules.lines() {
+    let page_rules_line: Vec<u32> = line.split("|").map(|s| s.parse().unwrap()).collect();
+    
+    let before_page = page_rules_line[0];
+    let after_page = page_rules_line[1];
+
+    let before_page_rules = page_rules
+      .entry(before_page)
+      .or_insert(OrderingSets { before: HashSet::new(), after: HashSet::new()});
+    before_page_rules.after.insert(after_page);
+
+    let after_page_rules = page_rules.entry(after_page)
+      .or_insert(OrderingSets { before: HashSet::new(), after: HashSet::new()});
+    after_page_rules.before.insert(before_page);
+  }
+
+  SafetyManual { rules: page_rules, updates: page_updates_list }
+}
+
+pub fn correct_incorrect_update(page_update: &[u32], page_ordering_rules_sets: &HashMap<u32, OrderingSets>) -> Vec<u32> {
+  let mut corrected_update = vec![];
+
+  let mut page_set = HashSet::new();
+  for page in page_update {
+    page_set.insert(page);
+  }
+
+  while !page_set.is_empty() {
+    for page in page_set.clone() {
+      let mut placeable = true;
+
+      for other_page in page_set.clone() {
+        if page == other_page {
+          continue;
+        }
+
+        if page_ordering_rules_sets.get(other_page).unwrap().after.contains(page) {
+          placeable = false;
+        }
+      }
+
+      if placeable {
+        corrected_update.push(*page);
+        page_set.remove(page);
+        break;
+      }
+    }
+  } 
+
+  // println!("update: {page_update:?}, corrected update: {corrected_update:?}");
+  corrected_update
+}
\ No newline at end of file
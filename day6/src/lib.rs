@@ -0,0 +1,145 @@
+/*
+ Advent of Code 2024 Day 6: Guard Gallivant
+
+ A guard is patrolling a lab. He is marked with a ^ in the puzzle input map.
+ The map is our input. Apart from the guard the map also shows obstructions shown as #
+ The guard patrol always follows this steps:
+  - If there is no obstacle, take a step forward.
+  - Otherwise, turn right 90 degrees.
+ The guard will always eventually leave the mapped area.
+ All positions visited by the guard are marked with an X.
+
+ Part one:
+
+ How many distinct positions will the guard visit before leaving the mapped area?
+
+ Part two:
+
+ How many positons could I put an obstruction and generate an infinite loop?
+
+ Solution:
+
+ The first part was pretty straight-forward with a solution a map of coordinates pointint o map elements.
+ Then the previous iterations were different but after seeing in internet other solutions I used the suggested set for tracking positions of the police.
+ In the second part the tricky part is to realize that the obstruction may have not been if the police started from the beginning, i.e. I was trying to check every time I was moving the police and that made that issue.
+ The correct, but brute force, solution is to check every next position for loops but starting from the police starting position. 
+*/
+use std::collections::HashSet;
+
+use std::fmt::Display;
+
+use utils::{find_marker, Coordinate, Direction, Grid, PuzzleInput, Result, Solution};
+
+pub struct GuardGallivant;
+
+impl Solution for GuardGallivant {
+  const DAY: u8 = 6;
+  const TITLE: &'static str = "Guard Gallivant";
+
+  type Input = Lab;
+
+  fn parse(input: &PuzzleInput) -> Result<Self::Input> {
+    parse_input(input)
+  }
+
+  fn part_one(lab: &Self::Input) -> impl Display {
+    calculate_positions(lab.guard.clone(), lab.map.clone())
+  }
+
+  fn part_two(lab: &Self::Input) -> impl Display {
+    calculate_obstructions(lab.guard.clone(), lab.map.clone())
+  }
+}
+
+/// The lab's map and where the guard starts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lab {
+  pub guard: Coordinate,
+  pub map: Grid<char>,
+}
+
+pub fn parse_input(input: &str) -> Result<Lab> {
+  let (puzzle_map, markers) = Grid::parse(input.lines(), &['^'], |item| item)?;
+
+  Ok(Lab { guard: find_marker(&markers, '^')?, map: puzzle_map })
+}
+
+pub fn calculate_obstructions(mut police_position: Coordinate, mut puzzle_map: Grid<char>) -> usize {
+  let mut police_direction = Direction::Up;
+  let mut obstacle_positions: HashSet<Coordinate> = HashSet::new();
+
+  let initial_police_position = police_position.clone();
+  let initial_police_direction = police_direction.clone();
+  let mut possible_position = police_position.add_delta(&police_direction);
+  while let Some(item) = puzzle_map.get(&possible_position) {
+    if *item == '#' {
+      police_direction = police_direction.rotate_right();
+    } else {
+      let mut clone_map = puzzle_map.clone();
+      if loops(possible_position.clone(), initial_police_position.clone(), initial_police_direction.clone(), &mut clone_map) {
+        obstacle_positions.insert(possible_position.clone());
+        puzzle_map[&possible_position] = 'O';
+      }
+      police_position = possible_position.clone();
+    }
+    
+    possible_position = police_position.add_delta(&police_direction);
+  }
+
+  obstacle_positions.len()
+}
+
+pub fn loops(obstacle: Coordinate,mut police_position: Coordinate, mut police_direction: Direction, puzzle_map: &mut Grid<char>) -> bool {
+  puzzle_map[&obstacle] = '#';
+  // directions the police has already walked each position in, one bit per direction
+  let mut visited_positions: Grid<u8> = Grid::filled(puzzle_map.width(), puzzle_map.height(), 0);
+  visited_positions[&police_position] |= direction_bit(&police_direction);
+  
+  let mut possible_position = police_position.add_delta(&police_direction);
+  while let Some(item) = puzzle_map.get(&possible_position) {
+    if *item == '#' {
+      police_direction = police_direction.rotate_right();
+    } else {
+      visited_positions[&police_position] |= direction_bit(&police_direction);
+      puzzle_map[&police_position] = 'X';
+      police_position = possible_position.clone();
+    }
+    
+    if visited_positions[&police_position] & direction_bit(&police_direction) != 0 {
+      return true;
+    }
+
+    possible_position = police_position.add_delta(&police_direction);
+  }
+
+  false
+}
+
+fn direction_bit(direction: &Direction) -> u8 {
+  match direction {
+    Direction::Up => 1,
+    Direction::Right => 2,
+    Direction::Down => 4,
+    Direction::Left => 8,
+    _ => unreachable!("The police only walks straight")
+  }
+}
+
+pub fn calculate_positions(mut police_position: Coordinate, puzzle_map: Grid<char>) -> usize {
+  let mut police_direction = Direction::Up;
+  let mut visited_positions: HashSet<Coordinate> = HashSet::from([police_position.clone()]);
+
+  let mut possible_position = police_position.add_delta(&police_direction);
+  while let Some(item) = puzzle_map.get(&possible_position) {
+    if *item == '#' {
+      police_direction = police_direction.rotate_right();
+    } else {
+      police_position = possible_position.clone();
+      visited_positions.insert(police_position.clone());
+    }
+    
+    possible_position = police_position.add_delta(&police_direction);
+  }
+
+  visited_positions.len()
+}
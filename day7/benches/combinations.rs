@@ -0,0 +1,36 @@
+/*
+ Compares the two ways of finding whether an equation's operators exist:
+ generating every operator combination (`exists_combination`) or trying them recursively (`exists_combination_recursive`).
+ The combinations for part two's three operators take around a minute on the puzzle input, so they're only compared on the example there.
+*/
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use day7::{total_calibration_result, total_calibration_result_recursive, BridgeRepair};
+use utils::{ChallengeConfig, TEST_CONFIG};
+
+fn compare_combinations(c: &mut Criterion) {
+  let example = TEST_CONFIG.load::<BridgeRepair>().unwrap();
+
+  for (part, operators) in [("part one", &["*", "+"][..]), ("part two", &["*", "+", "||"][..])] {
+    let mut group = c.benchmark_group(format!("example {part}"));
+    group.bench_function("combinations", |b| b.iter(|| total_calibration_result(example.clone(), operators)));
+    group.bench_function("recursive", |b| b.iter(|| total_calibration_result_recursive(example.clone(), operators)));
+    group.finish();
+  }
+
+  let puzzle = ChallengeConfig::default().load::<BridgeRepair>().unwrap();
+
+  let mut group = c.benchmark_group("puzzle part one");
+  group.sample_size(10);
+  group.bench_function("combinations", |b| b.iter(|| total_calibration_result(puzzle.clone(), &["*", "+"])));
+  group.bench_function("recursive", |b| b.iter(|| total_calibration_result_recursive(puzzle.clone(), &["*", "+"])));
+  group.finish();
+
+  let mut group = c.benchmark_group("puzzle part two");
+  group.sample_size(10);
+  group.bench_function("recursive", |b| b.iter(|| total_calibration_result_recursive(puzzle.clone(), &["*", "+", "||"])));
+  group.finish();
+}
+
+criterion_group!(benches, compare_combinations);
+criterion_main!(benches);
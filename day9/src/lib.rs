@@ -0,0 +1,198 @@
+/*
+ Advent of Code 2024 Day 9
+
+ Part one:
+
+ The input is a `disk map`, i.e. a series of characters in on line.
+ These represent the layout of `files` and `free space`.
+ Every file has an ID based on the order of the appearance before being rearranged.
+ The disk must be first compacted by filling the blank spaces with the files from the end to the beginning until they are all filled.
+ The result is to calculate the checksum which is the position (starting at 0) times the ID it has.
+
+ Part two:
+
+ similar to part one but now the files should be moved completely to the nearest free slot otherwise they are not moved
+*/
+use std::fmt::Display;
+
+use utils::{Error, PuzzleInput, Result, Solution};
+
+pub struct DiskFragmenter;
+
+impl Solution for DiskFragmenter {
+  const DAY: u8 = 9;
+  const TITLE: &'static str = "Disk Fragmenter";
+
+  type Input = Vec<FileInfo>;
+
+  fn parse(input: &PuzzleInput) -> Result<Self::Input> {
+    parse_disk_map(&parse_input(input))
+  }
+
+  fn part_one(disk_map: &Self::Input) -> impl Display {
+    filesystem_checksum(disk_map)
+  }
+
+  fn part_two(disk_map: &Self::Input) -> impl Display {
+    filesystem_checksum_improved(disk_map)
+  }
+}
+
+pub fn parse_input(input: &str) -> String {
+  let mut disk_map = String::new();
+  
+  for line in input.lines() {
+    disk_map = line.to_string();
+  }
+
+  disk_map
+}
+
+pub fn filesystem_checksum(disk_map: &[FileInfo]) -> i64 {
+  let expanded_disk_map = transform_disk_map(disk_map.to_vec());
+
+  let compacted_disk = compact_disk(expanded_disk_map);
+
+  let mut checksum: i64 = 0;
+  for (idx, item) in compacted_disk.iter().enumerate() {
+    if *item > 0 {
+      let partial: i64 = idx as i64 * item;
+      checksum += partial;
+    }
+  }
+
+  checksum
+} 
+
+fn compact_disk(mut expanded_disk_map: Vec<i64>) -> Vec<i64> {
+  let mut next_item_idx = expanded_disk_map.iter().rposition(|item| *item > 0).expect("Didn't find a file"); // For getting from the last position
+  for disk_idx in 0..expanded_disk_map.len() {
+    let current_item = &expanded_disk_map[disk_idx];
+    if *current_item >= 0|| next_item_idx < disk_idx {
+      continue;
+    }
+
+    expanded_disk_map.swap(disk_idx, next_item_idx);
+    // let partial: String = expanded_disk_map.iter().collect();
+    // println!("Swapped elements:  {partial}");
+
+    next_item_idx = expanded_disk_map.iter().rposition(|item| *item > 0).expect("Didn't a file"); // For getting from the last position
+  }
+
+  expanded_disk_map
+}
+
+pub fn filesystem_checksum_improved(disk_map: &[FileInfo]) -> i64 {
+  let rearranged_disk_map = rearrange_disk_map(disk_map.to_vec());
+
+  let transformed_disk_map = transform_disk_map(rearranged_disk_map);
+  // println!("transformed disk map: {:?}",  transformed_disk_map);
+
+  let mut checksum: i64 = 0;
+  for (idx, item) in transformed_disk_map.iter().enumerate() {
+    if *item > 0 {
+      let partial: i64 = idx as i64 * item;
+      checksum += partial;
+    }
+  }
+
+  checksum  
+}
+
+/// A block of the disk map, either a file or free space when its `file_id` is negative.
+#[derive(Debug, Clone)]
+pub struct FileInfo {
+  pub amount: u32,
+  pub file_id: i32,
+}
+
+/// Parses the disk map into its blocks, shared by both parts.
+pub fn parse_disk_map(disk_map: &str) -> Result<Vec<FileInfo>> {
+  let mut parsed_disk_map = Vec::new();
+
+  let mut file_id = 0;
+  let mut file_toggle = true;
+  for (idx, item) in disk_map.chars().enumerate() {
+    let amount = item.to_digit(10).ok_or_else(|| Error::parse(1, idx + 1, format!("Invalid disk map digit: {item}")))?;
+
+    if file_toggle  {
+      parsed_disk_map.push(FileInfo { amount, file_id });
+      file_id += 1;
+      file_toggle = false;
+    } else {
+      parsed_disk_map.push(FileInfo { amount, file_id: -1 });
+      file_toggle = true; 
+    }
+
+  }
+
+  Ok(parsed_disk_map)
+}
+
+fn transform_disk_map(parsed_disk_map: Vec<FileInfo>) -> Vec<i64> {
+  let mut disk_map = Vec::new();
+
+  for file_info in parsed_disk_map {
+    for _ in 0..file_info.amount {
+      disk_map.push(file_info.file_id as i64);
+    }
+  }
+
+  disk_map
+}
+
+fn rearrange_disk_map(mut parsed_disk_map: Vec<FileInfo>) -> Vec<FileInfo> {
+  let mut next_item_idx = parsed_disk_map
+                                .iter()
+                                .rposition(|item| item.file_id >= 0) // a valid file id
+                                .expect("Didn't find a file");
+
+  let mut next_item = parsed_disk_map[next_item_idx].clone();          
+  let mut current_file_id = next_item.file_id;
+
+  loop {
+    // println!("next_item_idx: {}, next_item: {:?}, current_file_id: {}", next_item_idx, next_item, current_file_id);
+
+    // get next valid file from the right
+    if next_item.file_id == 0 {
+      break;
+    }
+
+    let next_possible_place_idx: i32 = match parsed_disk_map
+                                  .iter().position(|item| item.file_id < 0 && item.amount >= next_item.amount) {
+                                    Some(value) => value as i32,
+                                    None => -1
+                                  };
+
+    // println!("next_possible_place_idx: {}", next_possible_place_idx);
+    if next_possible_place_idx < 0 || next_possible_place_idx > next_item_idx as i32 {
+      current_file_id -= 1;
+      next_item_idx = parsed_disk_map
+                                .iter()
+                                .rposition(|item| item.file_id == current_file_id) // a valid file id
+                                .expect("Didn't find a file");
+      next_item = parsed_disk_map[next_item_idx].clone();
+      continue; // if no space, skip number
+    } 
+
+    let possible_item = parsed_disk_map[next_possible_place_idx as usize].clone();
+    
+    if possible_item.amount == next_item.amount {
+      parsed_disk_map.swap(next_possible_place_idx as usize, next_item_idx);
+    } else {
+      parsed_disk_map[next_possible_place_idx as usize].amount -= next_item.amount;
+      parsed_disk_map[next_item_idx].file_id = -1;
+      parsed_disk_map.insert(next_possible_place_idx as usize, next_item);
+    }
+
+    current_file_id -= 1;
+    next_item_idx = parsed_disk_map
+                              .iter()
+                              .rposition(|item| item.file_id == current_file_id) // a valid file id
+                              .expect("Didn't find a file");
+    next_item = parsed_disk_map[next_item_idx].clone();
+  
+  }
+
+  parsed_disk_map
+}
\ No newline at end of file
@@ -0,0 +1,190 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use super::examples::value_to_string;
+use super::{ChallengeConfig, ChallengePart, Error, Result};
+
+/// Name of the file recording a day's accepted answers, next to its input files.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Answers a day's input is known to have, either given by the puzzle for an example or accepted for a puzzle input.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct KnownAnswers {
+  /// Whether they were recorded from a previous run, so a different answer means the solution changed.
+  pub recorded: bool,
+  pub part_one: Option<String>,
+  pub part_two: Option<String>,
+}
+
+/// How an answer compares with the known one.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnswerStatus {
+  Pass,
+  /// The answer isn't the one the example expects.
+  Fail { expected: String },
+  /// The answer isn't the one accepted for the puzzle input anymore.
+  Changed { accepted: String },
+  /// There is no known answer to compare it with.
+  Unknown,
+}
+
+/// Accepted answers of a day's puzzle inputs as recorded in its `answers.toml`, by input, e.g.
+///
+/// ```toml
+/// [puzzle]
+/// part_one = "3749"
+/// part_two = "11387"
+/// ```
+///
+/// The file lives next to the puzzle input, so each `AOC_INPUT_DIR` keeps the answers of its own inputs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Answers {
+  path: PathBuf,
+  inputs: BTreeMap<String, KnownAnswers>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawAnswers {
+  part_one: Option<toml::Value>,
+  part_two: Option<toml::Value>,
+}
+
+#[derive(Serialize)]
+struct RecordedAnswers<'a> {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  part_one: Option<&'a str>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  part_two: Option<&'a str>,
+}
+
+impl KnownAnswers {
+  /// Returns the known answer of a part, if any.
+  pub fn get(&self, part: ChallengePart) -> Option<&str> {
+    match part {
+      ChallengePart::One => self.part_one.as_deref(),
+      ChallengePart::Two => self.part_two.as_deref(),
+      ChallengePart::Both => None,
+    }
+  }
+
+  /// Compares the answer of a part with the known one.
+  pub fn check(&self, part: ChallengePart, answer: &str) -> AnswerStatus {
+    match self.get(part) {
+      None => AnswerStatus::Unknown,
+      Some(known) if known == answer => AnswerStatus::Pass,
+      Some(known) if self.recorded => AnswerStatus::Changed { accepted: known.to_string() },
+      Some(known) => AnswerStatus::Fail { expected: known.to_string() },
+    }
+  }
+}
+
+impl AnswerStatus {
+  /// Whether the answer is wrong or different from the accepted one.
+  pub fn is_regression(&self) -> bool {
+    matches!(self, Self::Fail { .. } | Self::Changed { .. })
+  }
+}
+
+impl Display for AnswerStatus {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Self::Pass => write!(f, "ok"),
+      Self::Fail { expected } => write!(f, "FAILED, expected {expected}"),
+      Self::Changed { accepted } => write!(f, "CHANGED, accepted {accepted}"),
+      Self::Unknown => write!(f, "not recorded"),
+    }
+  }
+}
+
+impl Answers {
+  /// Returns the accepted answers of a day, none when it has no `answers.toml`.
+  pub fn load(day: u8) -> Result<Self> {
+    let path = ChallengeConfig::input_dir(day).join(ANSWERS_FILE);
+    if !path.exists() {
+      return Ok(Self { path, inputs: BTreeMap::new() });
+    }
+
+    let answers = fs::read_to_string(&path).map_err(|cause| Error::Io { path: path.clone(), line: None, cause })?;
+    let answers: BTreeMap<String, RawAnswers> =
+      toml::from_str(&answers).map_err(|cause| Error::Manifest { path: path.clone(), cause: cause.to_string() })?;
+
+    let inputs = answers
+      .into_iter()
+      .map(|(input, raw)| {
+        let answers = KnownAnswers {
+          recorded: true,
+          part_one: raw.part_one.map(value_to_string),
+          part_two: raw.part_two.map(value_to_string),
+        };
+        (input, answers)
+      })
+      .collect();
+
+    Ok(Self { path, inputs })
+  }
+
+  /// Whether no answer has been accepted yet.
+  pub fn is_empty(&self) -> bool {
+    self.inputs.is_empty()
+  }
+
+  /// Names of the inputs with accepted answers, e.g. `puzzle`.
+  pub fn inputs(&self) -> impl Iterator<Item = &str> {
+    self.inputs.keys().map(|input| input.as_str())
+  }
+
+  /// Returns the accepted answers of an input.
+  pub fn get(&self, input: &str) -> KnownAnswers {
+    self.inputs.get(input).cloned().unwrap_or(KnownAnswers { recorded: true, ..KnownAnswers::default() })
+  }
+
+  /// Accepts the answer of a part for an input, replacing the previous one.
+  pub fn record(&mut self, input: &str, part: ChallengePart, answer: &str) {
+    let answers = self
+      .inputs
+      .entry(input.to_string())
+      .or_insert(KnownAnswers { recorded: true, ..KnownAnswers::default() });
+
+    match part {
+      ChallengePart::One => answers.part_one = Some(answer.to_string()),
+      ChallengePart::Two => answers.part_two = Some(answer.to_string()),
+      ChallengePart::Both => {},
+    }
+  }
+
+  /// Writes the answers back to the day's `answers.toml`.
+  pub fn save(&self) -> Result<()> {
+    let answers: BTreeMap<&str, RecordedAnswers> = self
+      .inputs
+      .iter()
+      .map(|(input, answers)| {
+        (input.as_str(), RecordedAnswers { part_one: answers.part_one.as_deref(), part_two: answers.part_two.as_deref() })
+      })
+      .collect();
+
+    let answers = toml::to_string(&answers).map_err(|cause| Error::Manifest { path: self.path.clone(), cause: cause.to_string() })?;
+
+    fs::write(&self.path, answers).map_err(|cause| Error::Save { path: self.path.clone(), cause })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn tells_failed_examples_from_changed_answers() {
+    let expected = KnownAnswers { recorded: false, part_one: Some("41".to_string()), part_two: None };
+    let accepted = KnownAnswers { recorded: true, ..expected.clone() };
+
+    assert_eq!(expected.check(ChallengePart::One, "41"), AnswerStatus::Pass);
+    assert_eq!(expected.check(ChallengePart::One, "42"), AnswerStatus::Fail { expected: "41".to_string() });
+    assert_eq!(accepted.check(ChallengePart::One, "42"), AnswerStatus::Changed { accepted: "41".to_string() });
+    assert_eq!(accepted.check(ChallengePart::Two, "6"), AnswerStatus::Unknown);
+  }
+}
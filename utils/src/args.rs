@@ -0,0 +1,160 @@
+use std::fmt;
+use std::path::PathBuf;
+
+use super::{ChallengeConfig, ChallengePart, InputSource};
+
+/// Options every day accepts, also used by the workspace runner.
+pub const OPTIONS_USAGE: &str = "\
+Options:
+  --part <1|2|both>   Part of the challenge to run. Defaults to part one.
+  -2, --two           Same as `--part 2`.
+  -t, --test          Uses the example input instead of the puzzle input.
+  --example <name>    Uses one of the examples listed in the day's `examples.toml`.
+  --input <path>      Reads the whole input from the given file instead, or from stdin with `-`.
+                      Input piped into stdin is used even without this option, unless it's `files`
+                      to read the day's input files no matter what.
+  --no-stdin          Same as `--input files`.
+  -q, --quiet         Only prints the answers.
+  --bench <runs>      Times parsing and both parts over that many runs, printing their min/median/max
+                      and writing a JSON summary to `target/bench/day<N>.json`.
+  -h, --help          Prints this help.";
+
+/// Reasons for not getting a configuration out of the command arguments.
+#[derive(Debug, PartialEq)]
+pub enum ArgsError {
+  /// The help was asked for, so nothing should be run.
+  Help,
+  /// The arguments are not valid.
+  Invalid(String),
+}
+
+impl fmt::Display for ArgsError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Self::Help => write!(f, "Help requested"),
+      Self::Invalid(message) => write!(f, "{message}"),
+    }
+  }
+}
+
+impl ChallengeConfig {
+  /// Builds the configuration out of the given command arguments, without the program name.
+  pub fn from_args<I: IntoIterator<Item = String>>(args: I) -> Result<Self, ArgsError> {
+    let mut config = Self::default();
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+      match arg.as_str() {
+        "-t" | "--test" => config.is_test = true,
+        "-2" | "--two" => config.part = ChallengePart::Two,
+        "-q" | "--quiet" => config.quiet = true,
+        "-h" | "--help" => return Err(ArgsError::Help),
+        "--no-stdin" => {
+          config.input = InputSource::Files;
+          config.piped_input = false;
+        },
+        "--part" => {
+          config.part = match value(&arg, args.next())?.as_str() {
+            "1" => ChallengePart::One,
+            "2" => ChallengePart::Two,
+            "both" => ChallengePart::Both,
+            part => return Err(ArgsError::Invalid(format!("Invalid part: {part}"))),
+          }
+        },
+        "--example" => {
+          config.example = Some(value(&arg, args.next())?);
+          config.is_test = true;
+        },
+        "--bench" => {
+          config.bench = match value(&arg, args.next())?.parse() {
+            Ok(runs) if runs > 0 => Some(runs),
+            _ => return Err(ArgsError::Invalid("The number of benchmark runs has to be a positive number".to_string())),
+          }
+        },
+        "--input" => {
+          match value(&arg, args.next())?.as_str() {
+            "-" => config.input = InputSource::Stdin,
+            "files" => {
+              config.input = InputSource::Files;
+              config.piped_input = false;
+            },
+            input_path => config.input = InputSource::Path(PathBuf::from(input_path)),
+          }
+        },
+        _ => return Err(ArgsError::Invalid(format!("Unknown argument: {arg}"))),
+      }
+    }
+
+    Ok(config)
+  }
+}
+
+fn value(flag: &str, value: Option<String>) -> Result<String, ArgsError> {
+  value.ok_or_else(|| ArgsError::Invalid(format!("Missing value for {flag}")))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn parse(args: &[&str]) -> Result<ChallengeConfig, ArgsError> {
+    ChallengeConfig::from_args(args.iter().map(|arg| arg.to_string()))
+  }
+
+  #[test]
+  fn defaults_to_part_one_with_the_puzzle_input() {
+    let config = parse(&[]).unwrap();
+
+    assert_eq!(config.part, ChallengePart::One);
+    assert!(!config.is_test);
+    assert!(!config.quiet);
+    assert_eq!(config.input, InputSource::Files);
+  }
+
+  #[test]
+  fn parses_every_option() {
+    let config = parse(&["--part", "both", "--example", "small", "--input", "other.txt", "--quiet", "--bench", "10"]).unwrap();
+
+    assert_eq!(config.part, ChallengePart::Both);
+    assert!(config.is_test);
+    assert_eq!(config.example, Some("small".to_string()));
+    assert_eq!(config.input, InputSource::Path(PathBuf::from("other.txt")));
+    assert!(config.quiet);
+    assert_eq!(config.bench, Some(10));
+  }
+
+  #[test]
+  fn keeps_the_short_aliases() {
+    let config = parse(&["-t", "-2"]).unwrap();
+
+    assert_eq!(config.part, ChallengePart::Two);
+    assert!(config.is_test);
+    assert_eq!(config.example, None);
+  }
+
+  #[test]
+  fn reads_stdin_with_a_dash() {
+    assert_eq!(parse(&["--input", "-"]).unwrap().input, InputSource::Stdin);
+  }
+
+  #[test]
+  fn can_ignore_piped_input() {
+    assert!(parse(&[]).unwrap().piped_input);
+
+    for args in [&["--input", "files"][..], &["--no-stdin"], &["--input", "-", "--no-stdin"]] {
+      let config = parse(args).unwrap();
+      assert_eq!(config.input, InputSource::Files, "{args:?}");
+      assert!(!config.piped_input, "{args:?}");
+    }
+  }
+
+  #[test]
+  fn rejects_invalid_arguments() {
+    assert_eq!(parse(&["--tset"]), Err(ArgsError::Invalid("Unknown argument: --tset".to_string())));
+    assert_eq!(parse(&["--part", "3"]), Err(ArgsError::Invalid("Invalid part: 3".to_string())));
+    assert_eq!(parse(&["--input"]), Err(ArgsError::Invalid("Missing value for --input".to_string())));
+    assert!(parse(&["--example"]).is_err());
+    assert!(parse(&["--bench", "0"]).is_err());
+    assert_eq!(parse(&["-t", "--help"]), Err(ArgsError::Help));
+  }
+}
@@ -0,0 +1,183 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use super::solution::timed;
+use super::{workspace_dir, ChallengeConfig, Error, Result, Solution};
+
+/// Shortest, median and longest time of a step over every benchmark run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct Timings {
+  #[serde(rename = "min_ns", with = "nanos")]
+  pub min: Duration,
+  #[serde(rename = "median_ns", with = "nanos")]
+  pub median: Duration,
+  #[serde(rename = "max_ns", with = "nanos")]
+  pub max: Duration,
+}
+
+/// Timings of a day's benchmark, as written to its JSON summary.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchSummary {
+  pub day: u8,
+  pub title: String,
+  pub input: String,
+  pub runs: usize,
+  pub parse: Timings,
+  pub part_one: Timings,
+  pub part_two: Timings,
+}
+
+impl Timings {
+  /// Sums up the durations of a step, which can't be empty.
+  pub fn of(mut durations: Vec<Duration>) -> Self {
+    durations.sort();
+
+    Self { min: durations[0], median: durations[durations.len() / 2], max: durations[durations.len() - 1] }
+  }
+}
+
+impl BenchSummary {
+  /// Returns the folder the summaries are written to, `target/bench` in the workspace.
+  pub fn dir() -> PathBuf {
+    workspace_dir().join("target").join("bench")
+  }
+
+  /// Returns the path of a day's summary, e.g. `target/bench/day7.json`.
+  pub fn path(day: u8) -> PathBuf {
+    Self::dir().join(format!("day{day}.json"))
+  }
+
+  /// Median time of parsing the input and solving both parts.
+  pub fn total(&self) -> Duration {
+    self.parse.median + self.part_one.median + self.part_two.median
+  }
+
+  /// Writes the summary as JSON, replacing the day's previous one.
+  pub fn save(&self) -> Result<PathBuf> {
+    let path = Self::path(self.day);
+    let save_error = |cause| Error::Save { path: path.clone(), cause };
+
+    fs::create_dir_all(Self::dir()).map_err(save_error)?;
+    let summary = serde_json::to_string_pretty(self).expect("summaries are plain data");
+    fs::write(&path, summary).map_err(save_error)?;
+
+    Ok(path)
+  }
+
+  /// Reads a day's summary, if it has been benchmarked.
+  pub fn load(day: u8) -> Result<Option<Self>> {
+    let path = Self::path(day);
+    if !path.exists() {
+      return Ok(None);
+    }
+
+    let summary = fs::read_to_string(&path).map_err(|cause| Error::Io { path: path.clone(), line: None, cause })?;
+
+    serde_json::from_str(&summary).map(Some).map_err(|cause| Error::Manifest { path, cause: cause.to_string() })
+  }
+}
+
+/// Parses the input and solves both parts the given number of times, timing each step.
+/// The input is read only once so the timings don't depend on the disk.
+pub fn bench<S: Solution>(config: &ChallengeConfig, runs: usize) -> Result<BenchSummary> {
+  let input = config.read_input::<S>()?;
+  let (mut parse, mut part_one, mut part_two) = (vec![], vec![], vec![]);
+
+  for _ in 0..runs.max(1) {
+    let (parsed, parse_time) = timed(|| S::parse(&input));
+    let parsed = parsed?;
+    parse.push(parse_time);
+
+    part_one.push(timed(|| S::part_one(&parsed).to_string()).1);
+    part_two.push(timed(|| S::part_two(&parsed).to_string()).1);
+  }
+
+  Ok(BenchSummary {
+    day: S::DAY,
+    title: S::TITLE.to_string(),
+    input: config.input_name(),
+    runs: parse.len(),
+    parse: Timings::of(parse),
+    part_one: Timings::of(part_one),
+    part_two: Timings::of(part_two),
+  })
+}
+
+/// Formats the summaries as a table of their median timings, with their totals in the last row.
+pub fn bench_table(summaries: &[BenchSummary]) -> String {
+  let mut table = format!(
+    "{:>3}  {:<24} {:>5} {:>12} {:>12} {:>12} {:>12}\n",
+    "Day", "Title", "Runs", "Parse", "Part One", "Part Two", "Total"
+  );
+
+  for summary in summaries {
+    table += &format!(
+      "{:>3}  {:<24} {:>5} {:>12} {:>12} {:>12} {:>12}\n",
+      summary.day,
+      summary.title,
+      summary.runs,
+      format!("{:.2?}", summary.parse.median),
+      format!("{:.2?}", summary.part_one.median),
+      format!("{:.2?}", summary.part_two.median),
+      format!("{:.2?}", summary.total()),
+    );
+  }
+
+  let total = |timings: fn(&BenchSummary) -> Duration| format!("{:.2?}", summaries.iter().map(timings).sum::<Duration>());
+  table += &format!(
+    "{:>3}  {:<24} {:>5} {:>12} {:>12} {:>12} {:>12}",
+    "",
+    "Total",
+    "",
+    total(|summary| summary.parse.median),
+    total(|summary| summary.part_one.median),
+    total(|summary| summary.part_two.median),
+    total(BenchSummary::total),
+  );
+
+  table
+}
+
+// Durations are written as whole nanoseconds so any tool can read them.
+mod nanos {
+  use std::time::Duration;
+
+  use serde::{Deserialize, Deserializer, Serializer};
+
+  pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(duration.as_nanos() as u64)
+  }
+
+  pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+    u64::deserialize(deserializer).map(Duration::from_nanos)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn sums_up_the_durations_in_any_order() {
+    let durations = [5, 1, 4, 2, 3].map(Duration::from_millis).to_vec();
+
+    let timings = Timings::of(durations);
+
+    assert_eq!(timings.min, Duration::from_millis(1));
+    assert_eq!(timings.median, Duration::from_millis(3));
+    assert_eq!(timings.max, Duration::from_millis(5));
+  }
+
+  #[test]
+  fn writes_timings_in_nanoseconds() {
+    let timings = Timings { min: Duration::from_micros(1), median: Duration::from_micros(2), max: Duration::from_micros(3) };
+
+    let json = serde_json::to_string(&timings).unwrap();
+
+    assert_eq!(json, r#"{"min_ns":1000,"median_ns":2000,"max_ns":3000}"#);
+    assert_eq!(serde_json::from_str::<Timings>(&json).unwrap(), timings);
+  }
+}
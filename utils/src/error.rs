@@ -0,0 +1,99 @@
+use std::error;
+use std::fmt::{self, Display};
+use std::io;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// Result type returned when reading or parsing a challenge's input.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Errors found while reading or parsing a challenge's input.
+#[derive(Debug)]
+pub enum Error {
+  /// An input file couldn't be opened, or one of its lines (starting at 1) couldn't be read.
+  Io { path: PathBuf, line: Option<usize>, cause: io::Error },
+  /// The input files are empty, e.g. the puzzle input wasn't added yet.
+  MissingInput(PathBuf),
+  /// Part of the input doesn't have the expected format. Lines and columns start at 1.
+  Parse { line: usize, column: usize, cause: String },
+  /// The map of the input doesn't have the marker, e.g. where to start from.
+  MissingMarker(char),
+  /// The input doesn't have the named section.
+  MissingSection(String),
+  /// A named section of the input couldn't be parsed, its lines are counted from the section's start.
+  Section { name: String, cause: Box<Error> },
+  /// A day's examples, answers or benchmark summary file isn't valid.
+  Manifest { path: PathBuf, cause: String },
+  /// A file couldn't be written.
+  Save { path: PathBuf, cause: io::Error },
+  /// The day has no example with that name.
+  UnknownExample(String),
+  /// A parameter of the input doesn't have the expected type.
+  InvalidParam { name: String, cause: String },
+}
+
+impl Error {
+  pub fn parse(line: usize, column: usize, cause: impl Display) -> Self {
+    Self::Parse { line, column, cause: cause.to_string() }
+  }
+}
+
+impl Display for Error {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Self::Io { path, line: None, cause } => write!(f, "Couldn't open {}: {cause}", path.display()),
+      Self::Io { path, line: Some(line), cause } => write!(f, "Couldn't read line {line} of {}: {cause}", path.display()),
+      Self::MissingInput(path) => write!(f, "Missing input, {} is empty", path.display()),
+      Self::Parse { line, column, cause } => write!(f, "Invalid input at line {line}, column {column}: {cause}"),
+      Self::MissingMarker(marker) => write!(f, "Missing `{marker}` in the map"),
+      Self::MissingSection(name) => write!(f, "Missing input section `{name}`"),
+      Self::Section { name, cause } => write!(f, "In section `{name}`: {cause}"),
+      Self::Manifest { path, cause } => write!(f, "Invalid {}: {cause}", path.display()),
+      Self::Save { path, cause } => write!(f, "Couldn't save {}: {cause}", path.display()),
+      Self::UnknownExample(name) => write!(f, "Unknown example `{name}`"),
+      Self::InvalidParam { name, cause } => write!(f, "Invalid parameter `{name}`: {cause}"),
+    }
+  }
+}
+
+impl error::Error for Error {
+  fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+    match self {
+      Self::Io { cause, .. } | Self::Save { cause, .. } => Some(cause),
+      Self::Section { cause, .. } => Some(cause.as_ref()),
+      _ => None,
+    }
+  }
+}
+
+/// Parses a field of an input line, pointing at it when it isn't valid.
+/// The field has to be a slice of the line so its column can be worked out.
+pub fn parse_field<T>(line: &str, line_number: usize, field: &str) -> Result<T>
+where
+  T: FromStr,
+  T::Err: Display,
+{
+  field.parse().map_err(|cause| Error::parse(line_number, column_of(line, field), format!("`{field}`: {cause}")))
+}
+
+/// Returns the column (starting at 1) where a slice of the line starts.
+pub fn column_of(line: &str, field: &str) -> usize {
+  let offset = (field.as_ptr() as usize).saturating_sub(line.as_ptr() as usize);
+
+  line.get(..offset.min(line.len())).map_or(1, |before| before.chars().count() + 1)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn points_at_the_invalid_field() {
+    let line = "12   x4";
+    let field = line.split_whitespace().nth(1).unwrap();
+
+    let error = parse_field::<i32>(line, 3, field).unwrap_err();
+
+    assert_eq!(error.to_string(), "Invalid input at line 3, column 6: `x4`: invalid digit found in string");
+  }
+}
@@ -0,0 +1,205 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use super::{read_file, ChallengeConfig, ChallengePart, Error, KnownAnswers, PuzzleInput, Result, Solution};
+
+/// Name of the file listing a day's examples, next to its input files.
+pub const EXAMPLES_FILE: &str = "examples.toml";
+
+/// An example of a day's puzzle as listed in its `examples.toml`, e.g.
+///
+/// ```toml
+/// [[example]]
+/// name = "small"
+/// file = "example_small_input.txt"
+/// params = { width = 11, height = 7 }
+/// part_one = 12
+/// ```
+///
+/// Sections can be read from their own files with `sections = { rules = "example_rules.txt", ... }` instead of `file`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Example {
+  pub name: String,
+  /// File holding the whole example, split into sections on blank lines.
+  pub file: Option<String>,
+  /// File of each section, when they are split like the puzzle input files.
+  pub sections: BTreeMap<String, String>,
+  /// Parameters the example uses instead of the puzzle's ones, e.g. a smaller room size.
+  pub params: BTreeMap<String, String>,
+  pub part_one: Option<String>,
+  pub part_two: Option<String>,
+}
+
+/// Answer to a part of an example compared with the expected one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExampleCheck {
+  pub example: String,
+  pub part: ChallengePart,
+  pub expected: String,
+  pub answer: String,
+}
+
+impl ExampleCheck {
+  pub fn passed(&self) -> bool {
+    self.expected == self.answer
+  }
+}
+
+#[derive(Deserialize)]
+struct Manifest {
+  #[serde(default)]
+  example: Vec<RawExample>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawExample {
+  name: String,
+  file: Option<String>,
+  #[serde(default)]
+  sections: BTreeMap<String, String>,
+  #[serde(default)]
+  params: BTreeMap<String, toml::Value>,
+  part_one: Option<toml::Value>,
+  part_two: Option<toml::Value>,
+}
+
+impl Example {
+  /// Returns the examples of a day, none when it has no `examples.toml`.
+  pub fn list(day: u8) -> Result<Vec<Example>> {
+    let path = ChallengeConfig::input_dir(day).join(EXAMPLES_FILE);
+    if !path.exists() {
+      return Ok(vec![]);
+    }
+
+    let manifest = fs::read_to_string(&path).map_err(|cause| Error::Io { path: path.clone(), line: None, cause })?;
+    let manifest: Manifest = toml::from_str(&manifest).map_err(|cause| Error::Manifest { path, cause: cause.to_string() })?;
+
+    Ok(manifest.example.into_iter().map(Example::from).collect())
+  }
+
+  /// Returns the example of a day with the given name.
+  pub fn find(day: u8, name: &str) -> Result<Example> {
+    Self::list(day)?
+      .into_iter()
+      .find(|example| example.name == name)
+      .ok_or_else(|| Error::UnknownExample(name.to_string()))
+  }
+
+  /// Reads the example input from the given folder, with its parameters.
+  pub fn read(&self, input_dir: &Path, section_names: &[&str]) -> Result<PuzzleInput> {
+    let input = match &self.file {
+      Some(file) => PuzzleInput::split(read_file(&input_dir.join(file))?.join("\n"), section_names),
+      None if !self.sections.is_empty() => {
+        let sections = section_names
+          .iter()
+          .map(|name| {
+            let file = self.sections.get(*name).ok_or_else(|| Error::MissingSection(name.to_string()))?;
+            Ok((name.to_string(), read_file(&input_dir.join(file))?.join("\n")))
+          })
+          .collect::<Result<Vec<(String, String)>>>()?;
+
+        PuzzleInput::from_sections(sections)
+      },
+      None => {
+        let cause = format!("Example `{}` has neither a `file` nor `sections`", self.name);
+        return Err(Error::Manifest { path: input_dir.join(EXAMPLES_FILE), cause });
+      },
+    };
+
+    Ok(input.with_params(self.params.clone()))
+  }
+
+  /// Returns the expected answer of a part, if known.
+  pub fn expected(&self, part: ChallengePart) -> Option<&str> {
+    match part {
+      ChallengePart::One => self.part_one.as_deref(),
+      ChallengePart::Two => self.part_two.as_deref(),
+      ChallengePart::Both => None,
+    }
+  }
+
+  /// Returns the answers the puzzle gives for the example.
+  pub fn answers(&self) -> KnownAnswers {
+    KnownAnswers { recorded: false, part_one: self.part_one.clone(), part_two: self.part_two.clone() }
+  }
+}
+
+impl From<RawExample> for Example {
+  fn from(raw: RawExample) -> Self {
+    Example {
+      name: raw.name,
+      file: raw.file,
+      sections: raw.sections,
+      params: raw.params.into_iter().map(|(name, value)| (name, value_to_string(value))).collect(),
+      part_one: raw.part_one.map(value_to_string),
+      part_two: raw.part_two.map(value_to_string),
+    }
+  }
+}
+
+// Strings are taken as they are so answers like `4,6,3` don't end up quoted.
+pub(super) fn value_to_string(value: toml::Value) -> String {
+  match value {
+    toml::Value::String(value) => value,
+    value => value.to_string(),
+  }
+}
+
+/// Solves every example of a day that has expected answers and compares them.
+pub fn verify<S: Solution>() -> Result<Vec<ExampleCheck>> {
+  let input_dir = ChallengeConfig::input_dir(S::DAY);
+  let mut checks = vec![];
+
+  for example in Example::list(S::DAY)? {
+    if example.part_one.is_none() && example.part_two.is_none() {
+      continue;
+    }
+
+    let input = S::parse(&example.read(&input_dir, S::SECTIONS)?)?;
+
+    for part in ChallengePart::Both.parts() {
+      let Some(expected) = example.expected(part) else {
+        continue;
+      };
+
+      let answer = match part {
+        ChallengePart::One => S::part_one(&input).to_string(),
+        _ => S::part_two(&input).to_string(),
+      };
+
+      checks.push(ExampleCheck { example: example.name.clone(), part, expected: expected.to_string(), answer });
+    }
+  }
+
+  Ok(checks)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn reads_answers_and_params_as_text() {
+    let manifest: Manifest = toml::from_str(
+      r#"
+        [[example]]
+        name = "small"
+        file = "example_input.txt"
+        params = { width = 11, height = 7 }
+        part_one = 12
+        part_two = "4,6,3"
+      "#,
+    )
+    .unwrap();
+
+    let example = Example::from(manifest.example.into_iter().next().unwrap());
+
+    assert_eq!(example.params["width"], "11");
+    assert_eq!(example.expected(ChallengePart::One), Some("12"));
+    assert_eq!(example.expected(ChallengePart::Two), Some("4,6,3"));
+  }
+}
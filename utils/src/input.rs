@@ -0,0 +1,137 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::ops::Deref;
+use std::str::FromStr;
+
+use super::{Error, Result};
+
+/// A challenge's raw input split into the named sections of the puzzle, e.g. day 5's `page_ordering_rules` and `pages`.
+/// It dereferences to the whole input, with its sections separated by a blank line as in the original puzzle input.
+/// Examples can also come with parameters that differ from the puzzle's, e.g. a smaller room.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PuzzleInput {
+  text: String,
+  sections: Vec<(String, String)>,
+  params: BTreeMap<String, String>,
+}
+
+impl PuzzleInput {
+  /// Builds an input with a single `input` section.
+  pub fn new(text: impl Into<String>) -> Self {
+    let text = text.into();
+
+    Self { sections: vec![("input".to_string(), text.clone())], text, params: BTreeMap::new() }
+  }
+
+  /// Builds an input out of its sections, e.g. one per input file.
+  pub fn from_sections(sections: Vec<(String, String)>) -> Self {
+    let text = sections.iter().map(|(_, section)| section.as_str()).collect::<Vec<&str>>().join("\n\n");
+
+    Self { text, sections, params: BTreeMap::new() }
+  }
+
+  /// Builds an input by splitting the text on blank lines into the given sections.
+  /// The last section holds whatever is left, and missing ones end up empty.
+  pub fn split(text: impl Into<String>, names: &[&str]) -> Self {
+    let text = text.into();
+    let mut parts = text.splitn(names.len().max(1), "\n\n");
+
+    let sections = names
+      .iter()
+      .map(|name| (name.to_string(), parts.next().unwrap_or_default().to_string()))
+      .collect();
+
+    Self { text, sections, params: BTreeMap::new() }
+  }
+
+  /// Sets the parameters of the input.
+  pub fn with_params(mut self, params: BTreeMap<String, String>) -> Self {
+    self.params = params;
+    self
+  }
+
+  /// Returns a parameter of the input, or the given default (the puzzle's value) when it isn't set.
+  pub fn param_or<T>(&self, name: &str, default: T) -> Result<T>
+  where
+    T: FromStr,
+    T::Err: Display,
+  {
+    match self.params.get(name) {
+      Some(value) => value.parse().map_err(|cause: T::Err| Error::InvalidParam { name: name.to_string(), cause: cause.to_string() }),
+      None => Ok(default),
+    }
+  }
+
+  /// Returns a section of the input by its name.
+  pub fn section(&self, name: &str) -> Result<&str> {
+    self
+      .sections
+      .iter()
+      .find(|(section_name, _)| section_name == name)
+      .map(|(_, section)| section.as_str())
+      .ok_or_else(|| Error::MissingSection(name.to_string()))
+  }
+
+  /// Parses a section of the input, telling which section it was when it fails.
+  pub fn parse_section<T>(&self, name: &str, parse: impl FnOnce(&str) -> Result<T>) -> Result<T> {
+    parse(self.section(name)?).map_err(|cause| Error::Section { name: name.to_string(), cause: Box::new(cause) })
+  }
+
+  pub fn as_str(&self) -> &str {
+    &self.text
+  }
+}
+
+impl Deref for PuzzleInput {
+  type Target = str;
+
+  fn deref(&self) -> &str {
+    &self.text
+  }
+}
+
+impl From<&str> for PuzzleInput {
+  fn from(text: &str) -> Self {
+    Self::new(text)
+  }
+}
+
+impl From<String> for PuzzleInput {
+  fn from(text: String) -> Self {
+    Self::new(text)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn splits_sections_on_blank_lines() {
+    let input = PuzzleInput::split("1|2\n3|4\n\n1,2\n3,4", &["rules", "pages"]);
+
+    assert_eq!(input.section("rules").unwrap(), "1|2\n3|4");
+    assert_eq!(input.section("pages").unwrap(), "1,2\n3,4");
+    assert!(matches!(input.section("other"), Err(Error::MissingSection(_))));
+  }
+
+  #[test]
+  fn joins_sections_with_a_blank_line() {
+    let input = PuzzleInput::from_sections(vec![
+      ("rules".to_string(), "1|2".to_string()),
+      ("pages".to_string(), "1,2".to_string()),
+    ]);
+
+    assert_eq!(&*input, "1|2\n\n1,2");
+    assert_eq!(input.section("pages").unwrap(), "1,2");
+  }
+
+  #[test]
+  fn params_default_to_the_puzzle_ones() {
+    let input = PuzzleInput::new("").with_params(BTreeMap::from([("width".to_string(), "11".to_string())]));
+
+    assert_eq!(input.param_or("width", 101).unwrap(), 11);
+    assert_eq!(input.param_or("height", 103).unwrap(), 103);
+    assert!(PuzzleInput::new("").with_params(BTreeMap::from([("width".to_string(), "x".to_string())])).param_or("width", 1).is_err());
+  }
+}
@@ -1,67 +1,387 @@
 use std::env;
 use std::fs::File;
 use std::io::{self, BufRead};
+#[cfg(not(unix))]
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+use std::process;
 
+mod answers;
+mod args;
+mod bench;
+mod error;
+mod examples;
+mod input;
+pub mod pathfinding;
+mod solution;
 mod topography;
 
+pub use answers::{AnswerStatus, Answers, KnownAnswers, ANSWERS_FILE};
+pub use args::{ArgsError, OPTIONS_USAGE};
+pub use bench::{bench, bench_table, BenchSummary, Timings};
+pub use error::{column_of, parse_field, Error, Result};
+pub use examples::{verify, Example, ExampleCheck, EXAMPLES_FILE};
+pub use input::PuzzleInput;
+pub use solution::{answers, run, solve, Solution};
+
 pub use topography::coordinate::Coordinate;
 pub use topography::direction::Direction;
+pub use topography::grid::{find_marker, Grid, Markers};
 pub use topography::map::*;
 
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ChallengePart {
   One,
-  Two
+  Two,
+  Both
+}
+
+impl ChallengePart {
+  /// Returns the single parts to run, in order.
+  pub fn parts(self) -> Vec<ChallengePart> {
+    match self {
+      Self::Both => vec![Self::One, Self::Two],
+      part => vec![part],
+    }
+  }
+}
+
+/// Where the challenge's input comes from.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum InputSource {
+  /// The day's example or puzzle files, in its input folder (see `ChallengeConfig::input_dir`).
+  #[default]
+  Files,
+  /// A single file holding the whole input.
+  Path(PathBuf),
+  /// The standard input, e.g. `--input -` or a pipe.
+  Stdin,
+  /// The input itself, e.g. for driving a day from another program.
+  Text(String),
 }
 
+#[derive(Debug, PartialEq)]
 pub struct ChallengeConfig {
   pub is_test: bool,
-  pub part: ChallengePart
+  pub part: ChallengePart,
+  /// Name of the example to use when testing, see `Example`. Defaults to the day's first one.
+  pub example: Option<String>,
+  pub input: InputSource,
+  /// Whether input piped into stdin replaces the input files, see `get`.
+  pub piped_input: bool,
+  /// Whether to only print the answers.
+  pub quiet: bool,
+  /// Number of times to run each step when benchmarking, see `bench`.
+  pub bench: Option<usize>,
 }
 
-impl ChallengeConfig {
-  const TEST_INPUT_FILE_PATH: &str = "./src/example_input.txt";
-  const PUZZLE_INPUT_FILE_PATH: &str = "./src/puzzle_input.txt";
+impl Default for ChallengeConfig {
+  fn default() -> Self {
+    Self { is_test: false, part: ChallengePart::One, example: None, input: InputSource::Files, piped_input: true, quiet: false, bench: None }
+  }
+}
 
+impl ChallengeConfig {
   /// Parses the command arguments and returns the current's challenge runtime config.
-  /// The default configuration is to run challenge's part one with the puzzle input. 
-  /// For the other configurations there are the following arguments:
-  ///   -2, --two: for running the second part of the challenge.
-  ///   -t, --test: for using the test input.
+  /// The default configuration is to run challenge's part one with the puzzle input,
+  /// see `OPTIONS_USAGE` for the other ones.
+  /// Prints the usage and exits when the help is asked for or the arguments are not valid.
+  /// Without `--input`, a pipe or file redirected into stdin is read as the input. It waits for whoever writes to it,
+  /// so commands run with an unrelated stdin, e.g. in a shell loop reading a file, should use `--input files` or `--no-stdin`.
   pub fn get() -> Self {
-    let is_test = env::args().any(|arg| ["-t", "--test"].contains(&arg.as_str()));
+    let mut args = env::args();
+    let program = args.next().unwrap_or_default();
+    let usage = format!("Usage: {program} [options]\n\n{OPTIONS_USAGE}");
 
-    let part = if env::args().any(|arg| ["-2", "--two"].contains(&arg.as_str())) {
-      ChallengePart::Two
-    } else {
-      ChallengePart::One
+    let mut config = match Self::from_args(args) {
+      Ok(config) => config,
+      Err(ArgsError::Help) => {
+        println!("{usage}");
+        process::exit(0);
+      },
+      Err(ArgsError::Invalid(message)) => {
+        eprintln!("{message}\n\n{usage}");
+        process::exit(2);
+      },
     };
 
-    println!("------ Running part: {:?}; Using: {} input -------", part, if is_test { "test" } else { "puzzle" } );
-  
-    Self { is_test, part }
+    // Piped input takes over the input files, unless nothing was actually piped.
+    if config.input == InputSource::Files && config.piped_input && is_stdin_piped() {
+      let is_piped = io::stdin().lock().fill_buf().is_ok_and(|buffer| !buffer.is_empty());
+      if is_piped {
+        config.input = InputSource::Stdin;
+      }
+    }
+
+    if !config.quiet && config.bench.is_some() {
+      println!("------ Benchmarking; Using: {} input -------", config.input_name());
+    } else if !config.quiet {
+      println!("------ Running part: {:?}; Using: {} input -------", config.part, config.input_name());
+    }
+
+    config
+  }
+
+  /// Describes the input in use, e.g. `puzzle`, `example small` or the given input path.
+  pub fn input_name(&self) -> String {
+    match (&self.input, self.is_test, &self.example) {
+      (InputSource::Path(input_path), _, _) => input_path.display().to_string(),
+      (InputSource::Stdin, _, _) => "stdin".to_string(),
+      (InputSource::Text(_), _, _) => "in-memory".to_string(),
+      (InputSource::Files, false, _) => "puzzle".to_string(),
+      (InputSource::Files, true, None) => "test".to_string(),
+      (InputSource::Files, true, Some(example)) => format!("example {example}"),
+    }
+  }
+
+  /// Returns an iterator on the lines of a day's input.
+  /// If no file path is specified it uses the challenge config's input source, the day's `input` file for the input files.
+  /// Panics when the input can't be read, see `try_read_puzzle_input` otherwise.
+  pub fn read_puzzle_input(&self, day: u8, file_path: Option<&str>) -> impl Iterator<Item = String> {
+    self.try_read_puzzle_input(day, file_path).unwrap_or_else(|error| panic!("{error}"))
   }
 
-  /// Returns an iterator on the input files.
-  /// If no file path is specified it uses the challenge config's defaults.
-  pub fn read_puzzle_input(&self, file_path: Option<&str>) -> impl Iterator<Item = String> {
-    let default_file_path = if self.is_test { 
-      Self::TEST_INPUT_FILE_PATH 
-    } else { 
-      Self::PUZZLE_INPUT_FILE_PATH 
+  /// Same as `read_puzzle_input` but returns an error with the path, line and cause when the input can't be read.
+  /// The whole input is read upfront so the returned lines can't fail anymore.
+  pub fn try_read_puzzle_input(&self, day: u8, file_path: Option<&str>) -> Result<impl Iterator<Item = String>> {
+    let lines = match (file_path, &self.input) {
+      (Some(file_path), _) => read_file(Path::new(file_path))?,
+      (None, InputSource::Files) => read_file(&Self::input_dir(day).join(self.input_file_name("input")))?,
+      (None, InputSource::Path(input_path)) => read_file(input_path)?,
+      (None, InputSource::Stdin) => read_lines(io::stdin().lock(), Path::new("<stdin>"))?,
+      (None, InputSource::Text(text)) => text.lines().map(String::from).collect(),
     };
-    
-    let file_path = file_path.unwrap_or(default_file_path);
-    
-    let file: File = File::open(file_path).expect(format!("Couldn't open {file_path}").as_str());
-    let reader = io::BufReader::new(file);
 
-    reader.lines().map(|line| line.expect("Error reading a line"))
+    Ok(lines.into_iter())
+  }
+
+  /// Returns the whole input of any reader, with its lines joined like when reading an input file.
+  pub fn read_from(reader: impl BufRead) -> Result<String> {
+    Ok(read_lines(reader, Path::new("<reader>"))?.join("\n"))
+  }
+
+  /// Returns the input of a solution split into its sections.
+  /// From the day's input folder (see `input_dir`) each section is read from its own file,
+  /// e.g. `pages` reads `example_pages.txt` or `puzzle_pages.txt`, unless there is a single `input` file holding all of them.
+  /// When testing, the examples listed in the day's `examples.toml` are used instead if there are any.
+  /// Any other input source holds every section already, separated by blank lines.
+  /// Input files with nothing but blank lines, e.g. a puzzle input that wasn't added yet, are an `Error::MissingInput`.
+  pub fn read_input<S: Solution>(&self) -> Result<PuzzleInput> {
+    if self.input != InputSource::Files {
+      let text = self.try_read_puzzle_input(S::DAY, None)?.collect::<Vec<String>>().join("\n");
+      return Ok(PuzzleInput::split(text, S::SECTIONS));
+    }
+
+    let input_dir = Self::input_dir(S::DAY);
+
+    if let Some(example) = self.example(S::DAY)? {
+      return example.read(&input_dir, S::SECTIONS);
+    }
+
+    let section_paths: Vec<PathBuf> = S::SECTIONS.iter().map(|name| input_dir.join(self.input_file_name(name))).collect();
+
+    let whole_input_path = input_dir.join(self.input_file_name("input"));
+    if !section_paths.iter().all(|path| path.exists()) && whole_input_path.exists() {
+      return non_empty(PuzzleInput::split(read_file(&whole_input_path)?.join("\n"), S::SECTIONS), &whole_input_path);
+    }
+
+    let sections = S::SECTIONS
+      .iter()
+      .zip(&section_paths)
+      .map(|(name, path)| Ok((name.to_string(), read_file(path)?.join("\n"))))
+      .collect::<Result<Vec<(String, String)>>>()?;
+
+    non_empty(PuzzleInput::from_sections(sections), &section_paths[0])
+  }
+
+  /// Returns the example to use when testing with the day's files: the named one or else the first one.
+  fn example(&self, day: u8) -> Result<Option<Example>> {
+    if !self.is_test || self.input != InputSource::Files {
+      return Ok(None);
+    }
+
+    match &self.example {
+      Some(name) => Example::find(day, name).map(Some),
+      None => Ok(Example::list(day)?.into_iter().next()),
+    }
+  }
+
+  /// Name the answers of the input are recorded under in the day's `answers.toml`:
+  /// `puzzle` for the puzzle files or the file name of another input. Examples and stdin have none.
+  pub fn answers_key(&self) -> Option<String> {
+    match &self.input {
+      InputSource::Files if !self.is_test => Some("puzzle".to_string()),
+      InputSource::Path(path) => path.file_name().map(|name| name.to_string_lossy().into_owned()),
+      _ => None,
+    }
+  }
+
+  /// Returns the answers the input is known to have: the expected ones of the example when testing,
+  /// or the ones accepted for the input in the day's `answers.toml`.
+  pub fn known_answers(&self, day: u8) -> Result<KnownAnswers> {
+    if let Some(example) = self.example(day)? {
+      return Ok(example.answers());
+    }
+
+    match self.answers_key() {
+      Some(input) => Ok(Answers::load(day)?.get(&input)),
+      None => Ok(KnownAnswers::default()),
+    }
+  }
+
+  /// Returns the folder holding a day's input files, i.e. its `src` folder in the workspace no matter where it's run from.
+  /// When `AOC_INPUT_DIR` is set, they're in its `day<N>` folder instead, e.g. `$AOC_INPUT_DIR/day5/puzzle_pages.txt`.
+  pub fn input_dir(day: u8) -> PathBuf {
+    match env::var_os("AOC_INPUT_DIR") {
+      Some(input_dir) => PathBuf::from(input_dir).join(format!("day{day}")),
+      None => workspace_dir().join(format!("day{day}")).join("src"),
+    }
+  }
+
+  /// Returns the name of an input file, e.g. `example_input.txt` when testing or `puzzle_input.txt` otherwise.
+  fn input_file_name(&self, file_name: &str) -> String {
+    let prefix = if self.is_test { "example" } else { "puzzle" };
+
+    format!("{prefix}_{file_name}.txt")
+  }
+
+  /// Reads and parses the input of a solution with this configuration.
+  pub fn load<S: Solution>(&self) -> Result<S::Input> {
+    S::parse(&self.read_input::<S>()?)
   }
+
+  /// Reads and parses the input of a solution from any reader, with its sections separated by blank lines.
+  pub fn load_from<S: Solution>(reader: impl BufRead) -> Result<S::Input> {
+    S::parse(&PuzzleInput::split(Self::read_from(reader)?, S::SECTIONS))
+  }
+}
+
+/// Returns the workspace folder, no matter where the command is run from.
+fn workspace_dir() -> PathBuf {
+  Path::new(env!("CARGO_MANIFEST_DIR")).parent().expect("utils is a member of the workspace").to_path_buf()
+}
+
+/// Whether stdin is a pipe or a redirected file, rather than a terminal or whatever was left open by who launched the command.
+#[cfg(unix)]
+fn is_stdin_piped() -> bool {
+  use std::os::fd::AsFd;
+  use std::os::unix::fs::FileTypeExt;
+
+  io::stdin()
+    .as_fd()
+    .try_clone_to_owned()
+    .map(File::from)
+    .and_then(|stdin| stdin.metadata())
+    .is_ok_and(|metadata| metadata.file_type().is_fifo() || metadata.is_file())
+}
+
+#[cfg(not(unix))]
+fn is_stdin_piped() -> bool {
+  !io::stdin().is_terminal()
+}
+
+fn non_empty(input: PuzzleInput, path: &Path) -> Result<PuzzleInput> {
+  if input.trim().is_empty() {
+    return Err(Error::MissingInput(path.to_path_buf()));
+  }
+
+  Ok(input)
+}
+
+fn read_file(path: &Path) -> Result<Vec<String>> {
+  let file = File::open(path).map_err(|cause| Error::Io { path: path.to_path_buf(), line: None, cause })?;
+
+  read_lines(io::BufReader::new(file), path)
+}
+
+fn read_lines(reader: impl BufRead, path: &Path) -> Result<Vec<String>> {
+  reader
+    .lines()
+    .enumerate()
+    .map(|(idx, line)| line.map_err(|cause| Error::Io { path: path.to_path_buf(), line: Some(idx + 1), cause }))
+    .collect()
 }
 
 pub const TEST_CONFIG: ChallengeConfig = ChallengeConfig {
   is_test: true,
   part: ChallengePart::One,
-};
\ No newline at end of file
+  example: None,
+  input: InputSource::Files,
+  piped_input: true,
+  quiet: false,
+  bench: None,
+};
+
+#[cfg(test)]
+mod tests {
+  use std::fmt::Display;
+  use std::fs;
+
+  use super::*;
+
+  // Reads one number per line.
+  struct Numbers;
+
+  impl Solution for Numbers {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Numbers";
+
+    type Input = Vec<u32>;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input> {
+      input.lines().enumerate().map(|(idx, line)| parse_field(line, idx + 1, line)).collect()
+    }
+
+    fn part_one(numbers: &Self::Input) -> impl Display {
+      numbers.iter().sum::<u32>()
+    }
+
+    fn part_two(numbers: &Self::Input) -> impl Display {
+      numbers.len()
+    }
+  }
+
+  #[test]
+  fn reads_any_reader_like_an_input_file() {
+    assert_eq!(ChallengeConfig::read_from("1\r\n10\n100\n".as_bytes()).unwrap(), "1\n10\n100");
+    assert_eq!(ChallengeConfig::load_from::<Numbers>("1\n10\n100\n2024\n".as_bytes()).unwrap(), [1, 10, 100, 2024]);
+  }
+
+  #[test]
+  fn reads_the_input_from_memory() {
+    let config = ChallengeConfig { input: InputSource::Text("1\n10\n100\n2024\n".to_string()), ..Default::default() };
+
+    assert_eq!(config.load::<Numbers>().unwrap(), [1, 10, 100, 2024]);
+    assert_eq!(config.input_name(), "in-memory");
+  }
+
+  #[test]
+  fn reads_stdin_only_when_asked_to() {
+    let config = ChallengeConfig { input: InputSource::Stdin, ..Default::default() };
+
+    assert_eq!(config.input_name(), "stdin");
+    assert_eq!(config.answers_key(), None);
+    assert_eq!(ChallengeConfig::default().input_name(), "puzzle");
+  }
+
+  #[test]
+  fn reads_the_input_files_of_the_given_day() {
+    let input_dir = env::temp_dir().join(format!("aoc-utils-{}", process::id()));
+    fs::create_dir_all(input_dir.join("day1")).unwrap();
+    fs::write(input_dir.join("day1").join("puzzle_input.txt"), "3\n4\n").unwrap();
+
+    // no other test reads a day's files, so they can't see the variable
+    env::set_var("AOC_INPUT_DIR", &input_dir);
+    let lines: Vec<String> = ChallengeConfig::default().try_read_puzzle_input(1, None).unwrap().collect();
+    let numbers = ChallengeConfig::default().load::<Numbers>();
+    fs::write(input_dir.join("day1").join("puzzle_input.txt"), "\n").unwrap();
+    let missing = ChallengeConfig::default().load::<Numbers>();
+    env::remove_var("AOC_INPUT_DIR");
+    fs::remove_dir_all(&input_dir).unwrap();
+
+    assert_eq!(lines, ["3", "4"]);
+    assert_eq!(numbers.unwrap(), [3, 4]);
+    assert!(matches!(missing, Err(Error::MissingInput(path)) if path.ends_with("day1/puzzle_input.txt")));
+  }
+}
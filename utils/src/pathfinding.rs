@@ -0,0 +1,268 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// What a search found: the distance to every state it reached and the states each one is reached from on its shortest paths.
+/// The goals are the ones reached at the shortest distance, e.g. a maze's end facing different directions.
+#[derive(Debug, Clone)]
+pub struct Search<S> {
+  pub start: S,
+  pub goals: Vec<S>,
+  pub distances: HashMap<S, usize>,
+  pub predecessors: HashMap<S, Vec<S>>,
+}
+
+impl<S: Clone + Eq + Hash> Search<S> {
+  fn new(start: S) -> Self {
+    Self { distances: HashMap::from([(start.clone(), 0)]), start, goals: vec![], predecessors: HashMap::new() }
+  }
+
+  /// Distance to the closest goal, if any was reached.
+  pub fn distance(&self) -> Option<usize> {
+    self.goals.first().and_then(|goal| self.distance_to(goal))
+  }
+
+  /// Distance to any reached state.
+  pub fn distance_to(&self, state: &S) -> Option<usize> {
+    self.distances.get(state).copied()
+  }
+
+  /// One of the shortest paths to the closest goal, from the start to the goal.
+  pub fn path(&self) -> Option<Vec<S>> {
+    self.goals.first().and_then(|goal| self.path_to(goal))
+  }
+
+  /// One of the shortest paths to any reached state, from the start to the state.
+  pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+    self.distances.get(state)?;
+
+    let mut path = vec![state.clone()];
+    while let Some(previous) = self.predecessors.get(path.last().unwrap()).and_then(|previous| previous.first()) {
+      path.push(previous.clone());
+    }
+    path.reverse();
+
+    Some(path)
+  }
+
+  /// Every shortest path to the goals. There can be a lot of them, see `on_shortest_paths` to only know the states they go through.
+  pub fn all_paths(&self) -> Vec<Vec<S>> {
+    let mut paths = vec![];
+    for goal in &self.goals {
+      self.collect_paths(&mut vec![goal.clone()], &mut paths);
+    }
+
+    paths
+  }
+
+  fn collect_paths(&self, path: &mut Vec<S>, paths: &mut Vec<Vec<S>>) {
+    match self.predecessors.get(path.last().unwrap()) {
+      Some(previous_states) => {
+        for previous in previous_states {
+          path.push(previous.clone());
+          self.collect_paths(path, paths);
+          path.pop();
+        }
+      },
+      None => paths.push(path.iter().rev().cloned().collect()),
+    }
+  }
+
+  /// Every state lying on any of the shortest paths to the goals, start and goals included.
+  pub fn on_shortest_paths(&self) -> HashSet<S> {
+    let mut states: HashSet<S> = self.goals.iter().cloned().collect();
+    let mut pending = self.goals.clone();
+
+    while let Some(state) = pending.pop() {
+      for previous in self.predecessors.get(&state).into_iter().flatten() {
+        if states.insert(previous.clone()) {
+          pending.push(previous.clone());
+        }
+      }
+    }
+
+    states
+  }
+
+  /// Keeps the state as reached from another one at the given distance, returns whether it's the first time or a shorter path.
+  fn reach(&mut self, state: &S, from: &S, distance: usize) -> bool {
+    match self.distances.get(state) {
+      Some(known) if *known < distance => false,
+      Some(known) if *known == distance => {
+        let previous_states = self.predecessors.entry(state.clone()).or_default();
+        if !previous_states.contains(from) {
+          previous_states.push(from.clone());
+        }
+        false
+      },
+      _ => {
+        self.distances.insert(state.clone(), distance);
+        self.predecessors.insert(state.clone(), vec![from.clone()]);
+        true
+      },
+    }
+  }
+}
+
+/// Breadth first search from the start, where every move costs 1, until the closest goals are reached.
+/// Use a goal that's never reached to get the distance to every reachable state.
+pub fn bfs<S, I>(start: S, mut neighbours: impl FnMut(&S) -> I, mut is_goal: impl FnMut(&S) -> bool) -> Search<S>
+where
+  S: Clone + Eq + Hash,
+  I: IntoIterator<Item = S>,
+{
+  let mut search = Search::new(start.clone());
+  let mut queue = VecDeque::from([start]);
+
+  while let Some(state) = queue.pop_front() {
+    let distance = search.distances[&state];
+    if search.distance().is_some_and(|goal_distance| distance > goal_distance) {
+      break;
+    }
+
+    if is_goal(&state) {
+      search.goals.push(state);
+      continue;
+    }
+
+    for next in neighbours(&state) {
+      if search.reach(&next, &state, distance + 1) {
+        queue.push_back(next);
+      }
+    }
+  }
+
+  search
+}
+
+/// Dijkstra's search from the start until the closest goals are reached, with the cost of moving from a state to its neighbours.
+/// Costs must be positive, panics on a move costing 0 as states at the same distance would be each other's predecessors.
+pub fn dijkstra<S, I>(
+  start: S,
+  neighbours: impl FnMut(&S) -> I,
+  cost: impl FnMut(&S, &S) -> usize,
+  is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+  S: Clone + Eq + Hash,
+  I: IntoIterator<Item = S>,
+{
+  astar(start, neighbours, cost, |_| 0, is_goal)
+}
+
+/// A* search from the start until the closest goals are reached, guided by an estimate of the cost left to reach a goal.
+/// The estimate can't be larger than the actual cost, or than the cost of a move plus the estimate from there, e.g. the manhattan distance on a grid.
+/// Costs must be positive, as for `dijkstra`.
+pub fn astar<S, I>(
+  start: S,
+  mut neighbours: impl FnMut(&S) -> I,
+  mut cost: impl FnMut(&S, &S) -> usize,
+  mut estimate: impl FnMut(&S) -> usize,
+  mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+  S: Clone + Eq + Hash,
+  I: IntoIterator<Item = S>,
+{
+  let mut search = Search::new(start.clone());
+  // the states are kept aside so they don't need to be ordered in the queue
+  let mut states = vec![(start.clone(), 0)];
+  let mut queue = BinaryHeap::from([Reverse((estimate(&start), 0))]);
+
+  while let Some(Reverse((estimated_distance, idx))) = queue.pop() {
+    let (state, distance) = states[idx].clone();
+    if distance > search.distances[&state] {
+      continue;
+    }
+    if search.distance().is_some_and(|goal_distance| estimated_distance > goal_distance) {
+      break;
+    }
+
+    if is_goal(&state) {
+      search.goals.push(state);
+      continue;
+    }
+
+    for next in neighbours(&state) {
+      let move_cost = cost(&state, &next);
+      assert!(move_cost > 0, "Search costs must be positive");
+      let next_distance = distance + move_cost;
+      if search.reach(&next, &state, next_distance) {
+        queue.push(Reverse((next_distance + estimate(&next), states.len())));
+        states.push((next, next_distance));
+      }
+    }
+  }
+
+  search
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{Coordinate, Grid};
+
+  fn maze() -> Grid<bool> {
+    Grid::parse(["S..#", ".#..", "...E"], &[], |tile| tile == '#').unwrap().0
+  }
+
+  fn open_neighbours<'a>(maze: &'a Grid<bool>) -> impl FnMut(&Coordinate) -> Vec<Coordinate> + 'a {
+    |tile| maze.neighbours(tile).filter(|next| !maze[next]).collect()
+  }
+
+  #[test]
+  fn finds_every_shortest_path() {
+    let maze = maze();
+    let end = Coordinate::new(2, 3);
+
+    let search = bfs(Coordinate::new(0, 0), open_neighbours(&maze), |tile| *tile == end);
+
+    assert_eq!(search.distance(), Some(5));
+    assert_eq!(search.path().unwrap().len(), 6);
+    assert_eq!(search.all_paths().len(), 3);
+    assert_eq!(search.on_shortest_paths().len(), 10);
+  }
+
+  #[test]
+  fn weighted_searches_agree() {
+    let maze = maze();
+    let end = Coordinate::new(2, 3);
+    // going down is twice as expensive
+    let cost = |from: &Coordinate, to: &Coordinate| if to.x > from.x { 2 } else { 1 };
+    let estimate = |tile: &Coordinate| tile.manhattan_distance(&end);
+
+    let dijkstra = dijkstra(Coordinate::new(0, 0), open_neighbours(&maze), cost, |tile| *tile == end);
+    let astar = astar(Coordinate::new(0, 0), open_neighbours(&maze), cost, estimate, |tile| *tile == end);
+
+    assert_eq!(dijkstra.distance(), Some(7));
+    assert_eq!(astar.distance(), Some(7));
+    assert_eq!(dijkstra.all_paths().len(), 3);
+    assert_eq!(dijkstra.on_shortest_paths(), astar.on_shortest_paths());
+  }
+
+  #[test]
+  #[should_panic(expected = "Search costs must be positive")]
+  fn rejects_moves_costing_nothing() {
+    // 1 and 2 are joined both ways for free, so each would be the other's predecessor
+    let neighbours = |state: &u8| match state {
+      0 => vec![1],
+      1 => vec![2],
+      2 => vec![1, 3],
+      _ => vec![],
+    };
+    let cost = |from: &u8, to: &u8| if (1..=2).contains(from) && (1..=2).contains(to) { 0 } else { 1 };
+
+    dijkstra(0, neighbours, cost, |state| *state == 3);
+  }
+
+  #[test]
+  fn reaches_nothing_when_walled_in() {
+    let maze = Grid::parse(["S#", "#E"], &[], |tile| tile == '#').unwrap().0;
+
+    let search = bfs(Coordinate::new(0, 0), open_neighbours(&maze), |tile| *tile == Coordinate::new(1, 1));
+
+    assert_eq!(search.distance(), None);
+    assert_eq!(search.path(), None);
+    assert!(search.all_paths().is_empty());
+  }
+}
@@ -0,0 +1,120 @@
+use std::fmt::Display;
+use std::process;
+use std::time::{Duration, Instant};
+
+use super::{bench, AnswerStatus, ChallengeConfig, ChallengePart, PuzzleInput, Result};
+
+/// A day's challenge split into parsing its input and solving each of its parts.
+/// Every day implements it so they can all be run, tested and timed the same way.
+pub trait Solution {
+  /// Day of the advent calendar the challenge belongs to.
+  const DAY: u8;
+  /// Title of the challenge as it appears in the puzzle.
+  const TITLE: &'static str;
+  /// Names of the input sections, in the order they appear in the puzzle input.
+  /// Each one is read from its own file, e.g. `pages` from `example_pages.txt` or `puzzle_pages.txt`,
+  /// or split on blank lines out of a single `input` file.
+  const SECTIONS: &'static [&'static str] = &["input"];
+
+  /// Parsed input shared by both parts.
+  type Input;
+
+  /// Parses the raw puzzle input.
+  fn parse(input: &PuzzleInput) -> Result<Self::Input>;
+
+  /// Solves part one of the challenge.
+  fn part_one(input: &Self::Input) -> impl Display;
+
+  /// Solves part two of the challenge.
+  fn part_two(input: &Self::Input) -> impl Display;
+}
+
+/// Runs a day's solution with the configuration from the command arguments.
+/// This is all a day's `main` needs to call.
+pub fn run<S: Solution>() {
+  let config = ChallengeConfig::get();
+
+  if let Err(error) = solve::<S>(&config) {
+    eprintln!("Day {} failed: {error}", S::DAY);
+    process::exit(1);
+  }
+}
+
+/// Parses the input and solves the configured parts, printing their answers and how long each step took.
+/// Answers are compared with the known ones of the input, see `ChallengeConfig::known_answers`.
+/// The input is parsed only once even when running both parts. In quiet mode only the answers are printed.
+/// When benchmarking, the timings are printed and saved instead, see `bench`.
+pub fn solve<S: Solution>(config: &ChallengeConfig) -> Result<()> {
+  if let Some(runs) = config.bench {
+    let summary = bench::<S>(config, runs)?;
+    let path = summary.save()?;
+
+    println!("{} runs, min / median / max", summary.runs);
+    for (step, timings) in [("Parse", summary.parse), ("Part One", summary.part_one), ("Part Two", summary.part_two)] {
+      println!("{step}: {:.2?} / {:.2?} / {:.2?}", timings.min, timings.median, timings.max);
+    }
+    println!("Summary written to {}", path.display());
+
+    return Ok(());
+  }
+
+  let input = config.read_input::<S>()?;
+  let known_answers = config.known_answers(S::DAY)?;
+
+  let (input, parse_time) = timed(|| S::parse(&input));
+  let input = input?;
+  if !config.quiet {
+    println!("Input parsed in {parse_time:?}");
+  }
+
+  let parts = config.part.parts();
+  let mut total_time = parse_time;
+
+  for part in &parts {
+    let (answer, part_time) = match part {
+      ChallengePart::One => timed(|| S::part_one(&input).to_string()),
+      ChallengePart::Two => timed(|| S::part_two(&input).to_string()),
+      ChallengePart::Both => unreachable!("Both is split into its single parts"),
+    };
+
+    if config.quiet {
+      println!("{answer}");
+    } else {
+      match known_answers.check(*part, &answer) {
+        AnswerStatus::Unknown => println!("Part {part:?}: {answer} ({part_time:?})"),
+        status => println!("Part {part:?}: {answer} ({part_time:?}) {status}"),
+      }
+    }
+    total_time += part_time;
+  }
+
+  if !config.quiet && parts.len() > 1 {
+    println!("Total time: {total_time:?}");
+  }
+
+  Ok(())
+}
+
+/// Parses the input and returns the answers of the configured parts, without printing anything.
+pub fn answers<S: Solution>(config: &ChallengeConfig) -> Result<Vec<(ChallengePart, String)>> {
+  let input = S::parse(&config.read_input::<S>()?)?;
+
+  let answers = config
+    .part
+    .parts()
+    .into_iter()
+    .map(|part| match part {
+      ChallengePart::One => (part, S::part_one(&input).to_string()),
+      _ => (part, S::part_two(&input).to_string()),
+    })
+    .collect();
+
+  Ok(answers)
+}
+
+pub(crate) fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+  let start = Instant::now();
+  let result = f();
+
+  (result, start.elapsed())
+}
@@ -1,3 +1,4 @@
 pub mod direction;
 pub mod coordinate;
+pub mod grid;
 pub mod map;
\ No newline at end of file
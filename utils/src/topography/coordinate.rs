@@ -18,6 +18,11 @@ impl Coordinate {
     (self.x < 0 || self.y < 0) || (self.x >= max.0 || self.y >= max.1 )
   }
 
+  /// Number of steps up, down, left or right to get to the other coordinate.
+  pub fn manhattan_distance(&self, coordinate: &Coordinate) -> usize {
+    (self.x.abs_diff(coordinate.x) + self.y.abs_diff(coordinate.y)) as usize
+  }
+
   pub fn add_delta(&self, direction: &Direction) -> Coordinate {
     match direction {
       Direction::Up =>        Coordinate { x: self.x - 1, y: self.y },
@@ -67,7 +72,7 @@ impl Coordinate {
     Option::None
   }
 
-  pub fn is_outer_edge(&self, direction: &Direction, surrounding_coordinates: &Vec<Coordinate>) -> bool {
+  pub fn is_outer_edge(&self, direction: &Direction, surrounding_coordinates: &[Coordinate]) -> bool {
     match direction {
       Direction::DownLeft => {
         !surrounding_coordinates.contains(&self.add_delta(&Direction::Left)) && 
@@ -89,7 +94,7 @@ impl Coordinate {
     }
   }
 
-  pub fn is_inner_edge(&self, direction: &Direction, surrounding_coordinates: &Vec<Coordinate>) -> bool {
+  pub fn is_inner_edge(&self, direction: &Direction, surrounding_coordinates: &[Coordinate]) -> bool {
     match direction {
       Direction::DownLeft => {
         surrounding_coordinates.contains(&self.add_delta(&Direction::Left)) && 
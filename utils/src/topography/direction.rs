@@ -45,6 +45,17 @@ impl Direction {
     }
   }
 
+  /// Rotates the direction 90 Degrees left.
+  pub fn rotate_left(&self) -> Direction {
+    match self { 
+      Direction::Up => Direction::Left, 
+      Direction::Left => Direction::Down,
+      Direction::Down => Direction::Right,
+      Direction::Right => Direction::Up,
+      _ => unreachable!()
+    }
+  }
+
   /// Returns an vector representation of the directions enum, good for iterating through them.
   /// Diagonals are not currently supported.
   pub fn iter() -> impl Iterator<Item = Direction> {
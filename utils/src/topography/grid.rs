@@ -0,0 +1,260 @@
+use std::collections::HashMap;
+use std::ops::{Index, IndexMut};
+
+use super::coordinate::Coordinate;
+use super::direction::Direction;
+use super::map::TopographicMap;
+use crate::{Error, Result};
+
+/// Where the marker characters asked for were found while parsing a grid, row after row.
+pub type Markers = HashMap<char, Vec<Coordinate>>;
+
+/// A rectangular map stored row after row, so getting to a location is just indexing instead of hashing.
+/// Like the rest of the maps, the coordinate's X is the row and Y the column.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+  width: usize,
+  height: usize,
+  cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+  /// Builds a grid out of its cells, row after row. Panics if there aren't `width * height` of them.
+  pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+    assert_eq!(cells.len(), width * height, "A {width}x{height} grid needs {} cells", width * height);
+
+    Self { width, height, cells }
+  }
+
+  /// Builds a grid with every cell set to the same value.
+  pub fn filled(width: usize, height: usize, value: T) -> Self
+  where
+    T: Clone,
+  {
+    Self { width, height, cells: vec![value; width * height] }
+  }
+
+  /// Number of columns.
+  pub fn width(&self) -> usize {
+    self.width
+  }
+
+  /// Number of rows.
+  pub fn height(&self) -> usize {
+    self.height
+  }
+
+  /// Whether the coordinate is inside the grid.
+  pub fn contains(&self, coordinate: &Coordinate) -> bool {
+    !coordinate.is_outside_boundaries((self.height as i32, self.width as i32))
+  }
+
+  pub fn get(&self, coordinate: &Coordinate) -> Option<&T> {
+    self.index_of(coordinate).map(|idx| &self.cells[idx])
+  }
+
+  pub fn get_mut(&mut self, coordinate: &Coordinate) -> Option<&mut T> {
+    self.index_of(coordinate).map(|idx| &mut self.cells[idx])
+  }
+
+  /// Returns the cells of a row, from left to right.
+  pub fn row(&self, x: usize) -> &[T] {
+    &self.cells[x * self.width..(x + 1) * self.width]
+  }
+
+  /// Returns the cells of a column, from top to bottom.
+  pub fn column(&self, y: usize) -> impl Iterator<Item = &T> {
+    self.cells.iter().skip(y).step_by(self.width).take(self.height)
+  }
+
+  /// Iterates through the rows, from top to bottom.
+  pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+    self.cells.chunks(self.width.max(1)).take(self.height)
+  }
+
+  /// Iterates through the columns, from left to right.
+  pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+    (0..self.width).map(|y| self.column(y))
+  }
+
+  /// Iterates through every coordinate of the grid, row after row.
+  pub fn coordinates(&self) -> impl Iterator<Item = Coordinate> {
+    let width = self.width;
+
+    (0..self.cells.len()).map(move |idx| Coordinate::new((idx / width) as i32, (idx % width) as i32))
+  }
+
+  /// Iterates through every cell with its coordinate, row after row.
+  pub fn iter(&self) -> impl Iterator<Item = (Coordinate, &T)> {
+    self.coordinates().zip(self.cells.iter())
+  }
+
+  /// Returns the coordinates up, down, left and right of the given one that are inside the grid.
+  pub fn neighbours(&self, coordinate: &Coordinate) -> impl Iterator<Item = Coordinate> + '_ {
+    let coordinate = coordinate.clone();
+
+    Direction::iter().map(move |direction| coordinate.add_delta(&direction)).filter(|next| self.contains(next))
+  }
+
+  /// Builds a grid of the same size with every cell transformed.
+  pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+    Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+  }
+
+  fn index_of(&self, coordinate: &Coordinate) -> Option<usize> {
+    self.contains(coordinate).then(|| coordinate.x as usize * self.width + coordinate.y as usize)
+  }
+}
+
+impl<T> Grid<T> {
+  /// Builds a grid turning each character of the lines, which need to be as long as the first one, into a cell.
+  /// Also returns where the given markers are, e.g. the start and end of a maze. Every marker has an entry even if it wasn't found.
+  pub fn parse<'a>(lines: impl IntoIterator<Item = &'a str>, markers: &[char], mut cell: impl FnMut(char) -> T) -> Result<(Self, Markers)> {
+    let mut found: Markers = markers.iter().map(|marker| (*marker, vec![])).collect();
+    let mut width = None;
+    let mut height = 0;
+    let mut cells = vec![];
+
+    for (x, line) in lines.into_iter().enumerate() {
+      let length = line.chars().count();
+      let width = *width.get_or_insert(length);
+      if length != width {
+        return Err(Error::parse(x + 1, length.min(width) + 1, format!("Expected {width} columns like the first line, found {length}")));
+      }
+
+      for (y, character) in line.chars().enumerate() {
+        if let Some(coordinates) = found.get_mut(&character) {
+          coordinates.push(Coordinate::new(x as i32, y as i32));
+        }
+        cells.push(cell(character));
+      }
+      height += 1;
+    }
+
+    Ok((Self { width: width.unwrap_or(0), height, cells }, found))
+  }
+}
+
+impl Grid<char> {
+  /// Builds a grid out of the characters of each line, which need to be as long as the first one.
+  pub fn from_lines<'a>(lines: impl IntoIterator<Item = &'a str>) -> Result<Self> {
+    Self::parse(lines, &[], |character| character).map(|(grid, _)| grid)
+  }
+}
+
+/// Returns the first place a marker was found, failing when the map doesn't have it.
+pub fn find_marker(markers: &Markers, marker: char) -> Result<Coordinate> {
+  markers.get(&marker).and_then(|coordinates| coordinates.first()).cloned().ok_or(Error::MissingMarker(marker))
+}
+
+impl<T: Clone> Grid<T> {
+  /// Builds the smallest grid holding every location of the map, the ones missing are filled with the given value.
+  /// Locations with negative coordinates are left out.
+  pub fn from_map(map: &TopographicMap<T>, fill: T) -> Self {
+    let height = map.keys().map(|coordinate| coordinate.x + 1).max().unwrap_or(0).max(0) as usize;
+    let width = map.keys().map(|coordinate| coordinate.y + 1).max().unwrap_or(0).max(0) as usize;
+
+    let mut grid = Self::filled(width, height, fill);
+    for (coordinate, value) in map {
+      if let Some(cell) = grid.get_mut(coordinate) {
+        *cell = value.clone();
+      }
+    }
+
+    grid
+  }
+
+  /// Returns every location of the grid in a map.
+  pub fn to_map(&self) -> TopographicMap<T> {
+    self.iter().map(|(coordinate, value)| (coordinate, value.clone())).collect()
+  }
+}
+
+impl<T> Index<&Coordinate> for Grid<T> {
+  type Output = T;
+
+  /// Panics when the coordinate is outside the grid, see `get` otherwise.
+  fn index(&self, coordinate: &Coordinate) -> &T {
+    match self.index_of(coordinate) {
+      Some(idx) => &self.cells[idx],
+      None => panic!("{coordinate:?} is outside the {}x{} grid", self.width, self.height),
+    }
+  }
+}
+
+impl<T> IndexMut<&Coordinate> for Grid<T> {
+  fn index_mut(&mut self, coordinate: &Coordinate) -> &mut T {
+    match self.index_of(coordinate) {
+      Some(idx) => &mut self.cells[idx],
+      None => panic!("{coordinate:?} is outside the {}x{} grid", self.width, self.height),
+    }
+  }
+}
+
+impl<T> From<Grid<T>> for TopographicMap<T> {
+  fn from(grid: Grid<T>) -> Self {
+    let width = grid.width;
+
+    grid
+      .cells
+      .into_iter()
+      .enumerate()
+      .map(|(idx, value)| (Coordinate::new((idx / width) as i32, (idx % width) as i32), value))
+      .collect()
+  }
+}
+
+impl<T: Clone + Default> From<&TopographicMap<T>> for Grid<T> {
+  fn from(map: &TopographicMap<T>) -> Self {
+    Self::from_map(map, T::default())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn indexes_by_row_and_column() {
+    let mut grid = Grid::from_lines(["abc", "def"]).unwrap();
+
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[&Coordinate::new(1, 0)], 'd');
+    assert_eq!(grid.get(&Coordinate::new(0, 3)), None);
+    assert_eq!(grid.get(&Coordinate::new(-1, 0)), None);
+
+    *grid.get_mut(&Coordinate::new(0, 2)).unwrap() = 'x';
+    assert_eq!(grid.row(0), ['a', 'b', 'x']);
+    assert_eq!(grid.column(1).collect::<String>(), "be");
+    assert_eq!(grid.columns().map(|column| column.collect::<String>()).collect::<Vec<_>>(), ["ad", "be", "xf"]);
+  }
+
+  #[test]
+  fn rejects_lines_of_different_lengths() {
+    let error = Grid::from_lines(["abc", "de"]).unwrap_err();
+
+    assert_eq!(error.to_string(), "Invalid input at line 2, column 3: Expected 3 columns like the first line, found 2");
+  }
+
+  #[test]
+  fn finds_the_markers_while_parsing() {
+    let (grid, markers) = Grid::parse(["S.#", "#.E", "..E"], &['S', 'E', '^'], |character| character == '#').unwrap();
+
+    assert_eq!(grid.row(1), [true, false, false]);
+    assert_eq!(markers[&'E'], [Coordinate::new(1, 2), Coordinate::new(2, 2)]);
+    assert_eq!(find_marker(&markers, 'S').unwrap(), Coordinate::new(0, 0));
+    assert_eq!(find_marker(&markers, '^').unwrap_err().to_string(), "Missing `^` in the map");
+  }
+
+  #[test]
+  fn converts_to_and_from_topographic_maps() {
+    let grid = Grid::from_lines(["ab", "cd"]).unwrap();
+
+    let map = TopographicMap::from(grid.clone());
+    assert_eq!(map[&Coordinate::new(1, 0)], 'c');
+    assert_eq!(Grid::from(&map), grid);
+
+    let sparse = TopographicMap::from([(Coordinate::new(1, 2), '#')]);
+    assert_eq!(Grid::from_map(&sparse, '.'), Grid::from_lines(["...", "..#"]).unwrap());
+  }
+}
@@ -17,7 +17,7 @@ pub fn print_coordinate_map(map: &TopographicMap<char>) {
   for idx in 0..max_x + 1 {
     let mut line = Vec::new();
     for idy in 0..max_y + 1 {
-      let coordinate = Coordinate { x: idx as i32, y: idy as i32};
+      let coordinate = Coordinate { x: idx, y: idy };
       if let Some(item) = map.get(&coordinate) {
         line.push(item.to_string());
       } else {
@@ -25,7 +25,7 @@ pub fn print_coordinate_map(map: &TopographicMap<char>) {
       }
     }
 
-    if line.len() > 0 && !line.iter().all(|c| c == " "){
+    if !line.is_empty() && !line.iter().all(|c| c == " "){
       println!("{}", line.concat())
     }
   }